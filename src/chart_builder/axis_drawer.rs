@@ -2,6 +2,7 @@
 
 
 use chart_builder::*;
+use data_parser;
 
 
 pub(in chart_builder) fn calc_zero_intercept(axis_min: f64, axis_max: f64) -> f64 {
@@ -29,9 +30,10 @@ pub(in chart_builder) fn calc_y_intercept(zero_intercept: f64, _horizontal_scali
 }
 
 // Responsible for drawing a catagorical x-axis
+// label_interval specifies how often a label is shown, e.g. 2 labels every other category.
 pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
         data_labels: &Vec<String>, x_axis_scale: f64, zero_intercept: f64, axis_title: &String,
-        screen_size: (f64, f64), fill: bool) {
+        screen_size: (f64, f64), fill: bool, label_interval: usize) {
         let _horizontal_scaling = scalings.0;
         let _vertical_scaling = scalings.1;
         let _left_bound = scalings.2;
@@ -53,6 +55,8 @@ pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64,
 
     let x_delimiter_interval: f64 = _horizontal_scaling * x_axis_scale;
     let x_delimiter_length = 0.015 * v_scale;
+    // space available for each label
+    let label_width = x_delimiter_interval * (label_interval as f64);
 
     cr.set_source_rgb(0.0, 0.0, 0.0);
 
@@ -63,7 +67,7 @@ pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64,
     cr.stroke();
     // Display x-axis delimiters - catagorical
     cr.set_line_width(0.002 * h_scale);
-    for i in 0..data_labels.len() {
+    for i in (0..data_labels.len()).filter(|i| i % label_interval == 0) {
         if fill == false {
             cr.move_to(_left_bound - (x_delimiter_interval / 2.0) + x_delimiter_interval * ((i + 1) as f64), intercept - (x_delimiter_length / 2.0));
         } else {
@@ -80,7 +84,7 @@ pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64,
     cr.set_font_matrix(font_matrix);
 
     let num_labels = data_labels.len();
-    for i in 0..num_labels {
+    for i in (0..num_labels).filter(|i| i % label_interval == 0) {
         let mut axis_string = data_labels[i].clone();
         // stores the partial string as the characters are popped off
         let mut axis_string_temp = axis_string.clone();
@@ -90,7 +94,7 @@ pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64,
             {
                 let axis_str = axis_string.as_str();
                 let text_width = cr.text_extents(axis_str).width;
-                if text_width > x_delimiter_interval {fits = false}
+                if text_width > label_width {fits = false}
             }
 
             if !fits {
@@ -143,6 +147,19 @@ pub(in chart_builder) fn draw_x_axis_cat(cr: &Context, scalings: (f64, f64, f64,
 pub(in chart_builder) fn draw_x_axis_con(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
         x_axis_min: f64, x_axis_max: f64, x_axis_scale: f64, zero_intercept: f64, axis_title: &String,
         screen_size: (f64, f64)) {
+    draw_x_axis_con_labelled(cr, scalings, x_axis_min, x_axis_max, x_axis_scale, zero_intercept, axis_title, screen_size, false);
+}
+
+// Responsible for drawing a continious x-axis where values are days since 1970-01-01, labelled as dates
+pub(in chart_builder) fn draw_x_axis_date(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
+        x_axis_min: f64, x_axis_max: f64, x_axis_scale: f64, zero_intercept: f64, axis_title: &String,
+        screen_size: (f64, f64)) {
+    draw_x_axis_con_labelled(cr, scalings, x_axis_min, x_axis_max, x_axis_scale, zero_intercept, axis_title, screen_size, true);
+}
+
+fn draw_x_axis_con_labelled(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
+        x_axis_min: f64, x_axis_max: f64, x_axis_scale: f64, zero_intercept: f64, axis_title: &String,
+        screen_size: (f64, f64), date_labels: bool) {
         let _horizontal_scaling = scalings.0;
         let _vertical_scaling = scalings.1;
         let _left_bound = scalings.2;
@@ -202,7 +219,9 @@ pub(in chart_builder) fn draw_x_axis_con(cr: &Context, scalings: (f64, f64, f64,
         let axis_num = x_axis_min + ((x_axis_max - x_axis_min) * x_axis_scale * (i as f64));
 
         let mut axis_num_string: String;
-        if e_format == true {
+        if date_labels == true {
+            axis_num_string = data_parser::num_to_date(axis_num.round());
+        } else if e_format == true {
             axis_num_string = format!("{:e}", format!("{:.*}", 15, axis_num).parse::<f64>().unwrap() ).to_string();
         } else {
             axis_num_string = format!("{:.*}", x_dps, axis_num).to_string();
@@ -252,6 +271,19 @@ pub(in chart_builder) fn draw_x_axis_con(cr: &Context, scalings: (f64, f64, f64,
 pub(in chart_builder) fn draw_y_axis_con(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
         y_axis_min: f64, y_axis_max: f64, y_axis_scale: f64, zero_intercept: f64, axis_title: &String,
         screen_size: (f64, f64)) {
+    draw_y_axis_con_labelled(cr, scalings, y_axis_min, y_axis_max, y_axis_scale, zero_intercept, axis_title, screen_size, false);
+}
+
+// Responsible for drawing a logarithmic y-axis where values are powers of 10, labelled as 1e<power>
+pub(in chart_builder) fn draw_y_axis_log(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
+        y_axis_min: f64, y_axis_max: f64, y_axis_scale: f64, zero_intercept: f64, axis_title: &String,
+        screen_size: (f64, f64)) {
+    draw_y_axis_con_labelled(cr, scalings, y_axis_min, y_axis_max, y_axis_scale, zero_intercept, axis_title, screen_size, true);
+}
+
+fn draw_y_axis_con_labelled(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
+        y_axis_min: f64, y_axis_max: f64, y_axis_scale: f64, zero_intercept: f64, axis_title: &String,
+        screen_size: (f64, f64), log_labels: bool) {
         let _horizontal_scaling = scalings.0;
         let _vertical_scaling = scalings.1;
         let _left_bound = scalings.2;
@@ -260,7 +292,8 @@ pub(in chart_builder) fn draw_y_axis_con(cr: &Context, scalings: (f64, f64, f64,
         let _upper_bound = scalings.5;
 
     let intercept = calc_y_intercept(zero_intercept, _horizontal_scaling, _left_bound, _right_bound);
-    let other_zero_intercept = calc_zero_intercept(y_axis_min, y_axis_max);
+    // a logarithmic axis never reaches zero, so the x-axis is always along the bottom
+    let other_zero_intercept = if log_labels == true { 0.0 } else { calc_zero_intercept(y_axis_min, y_axis_max) };
     let other_intercept = calc_x_intercept(other_zero_intercept, _vertical_scaling, _lower_bound, _upper_bound);
 
     let mut h_scale = screen_size.1 / screen_size.0;
@@ -312,7 +345,9 @@ pub(in chart_builder) fn draw_y_axis_con(cr: &Context, scalings: (f64, f64, f64,
         let axis_num = y_axis_min + ((y_axis_max - y_axis_min) * y_axis_scale * (i as f64));
 
         let mut axis_num_string: String;
-        if e_format == true {
+        if log_labels == true {
+            axis_num_string = if axis_num.round() == 0.0 { String::from("1") } else { format!("1e{}", axis_num.round()) };
+        } else if e_format == true {
             axis_num_string = format!("{:e}", format!("{:.*}", 15, axis_num).parse::<f64>().unwrap() ).to_string();
             if axis_num == 0.0 {
                 axis_num_string = format!("{:.*}", 0, axis_num).to_string();
@@ -361,3 +396,84 @@ pub(in chart_builder) fn draw_y_axis_con(cr: &Context, scalings: (f64, f64, f64,
     cr.move_to(_left_bound - y_delimiter_length * 0.5 - max_str - axis_title_width, _upper_bound + axis_title_height);
     cr.show_text(axis_title_str);
 }
+
+// Responsible for drawing a catagorical y-axis, with the first label at the top
+pub(in chart_builder) fn draw_y_axis_cat(cr: &Context, scalings: (f64, f64, f64, f64 ,f64, f64),
+        data_labels: &Vec<String>, y_axis_scale: f64, zero_intercept: f64, axis_title: &String,
+        screen_size: (f64, f64)) {
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+    let intercept = calc_y_intercept(zero_intercept, _horizontal_scaling, _left_bound, _right_bound);
+
+    let mut h_scale = screen_size.1 / screen_size.0;
+    let mut v_scale = screen_size.0 / screen_size.1;
+
+    // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+    if h_scale < v_scale {
+        v_scale = 1.0;
+    } else {
+        h_scale = 1.0;
+    }
+
+    let y_delimiter_interval: f64 = _vertical_scaling * y_axis_scale;
+    let y_delimiter_length = 0.015 * h_scale;
+
+    cr.set_source_rgb(0.0, 0.0, 0.0);
+
+    // Display y-axis full line
+    cr.set_line_width(0.002 * h_scale);
+    cr.move_to(intercept, _lower_bound);
+    cr.rel_line_to(0.0, -_vertical_scaling);
+    cr.stroke();
+    // Display y-axis delimiters - catagorical
+    cr.set_line_width(0.002 * v_scale);
+    for i in 0..data_labels.len() {
+        cr.move_to(intercept - (y_delimiter_length / 2.0), _upper_bound - (y_delimiter_interval / 2.0) + y_delimiter_interval * ((i + 1) as f64));
+        cr.rel_line_to(y_delimiter_length, 0.0);
+    }
+    cr.stroke();
+
+    // Display y-axis strings - catagorical
+    cr.set_font_size(0.02);
+    let mut font_matrix = cr.get_font_matrix();
+    font_matrix.scale(h_scale, v_scale);
+    cr.set_font_matrix(font_matrix);
+
+    // labels must fit in the space left of the axis
+    let max_label_width = _left_bound * 0.8;
+
+    for i in 0..data_labels.len() {
+        let mut axis_string = data_labels[i].clone();
+        // stores the partial string as the characters are popped off
+        let mut axis_string_temp = axis_string.clone();
+        while cr.text_extents(axis_string.as_str()).width > max_label_width && axis_string_temp.len() > 0 {
+            axis_string_temp.pop();
+            axis_string = axis_string_temp.clone();
+            axis_string.push_str("...");
+        }
+
+        let axis_str = axis_string.as_str();
+        let text_height = cr.text_extents(axis_str).height;
+        let text_width = cr.text_extents(axis_str).width;
+
+        let y_pos = _upper_bound - (y_delimiter_interval / 2.0) + y_delimiter_interval * ((i + 1) as f64);
+        cr.move_to(intercept - y_delimiter_length - text_width, y_pos + text_height / 2.0);
+        cr.show_text(axis_str);
+    }
+
+    // draw y axis titles
+    let axis_title_str = axis_title.as_str();
+    font_matrix.scale(1.1, 1.1);
+    cr.set_font_matrix(font_matrix);
+
+    let axis_title_width = cr.text_extents(axis_title_str).width;
+    let axis_title_height = cr.text_extents(axis_title_str).height;
+
+    cr.move_to((_left_bound - axis_title_width).max(0.0), _upper_bound - axis_title_height);
+    cr.show_text(axis_title_str);
+}
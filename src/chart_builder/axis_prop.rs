@@ -172,8 +172,25 @@ fn calc_scale(min: f64, max: f64) -> f64 {
 }
 
 pub(in chart_builder) fn calc_axis_props(data: &Vec<Vec<f64>>, start_zero: bool, x_axis: bool) -> ((f64, f64), f64) {
+    calc_error_axis_props(data, &Vec::new(), &Vec::new(), start_zero, x_axis)
+}
+
+// Same as calc_axis_props but the axis range also includes the extent of error bars,
+// lower_errors below and upper_errors above each value (missing errors are taken as 0).
+pub(in chart_builder) fn calc_error_axis_props(data: &Vec<Vec<f64>>, lower_errors: &Vec<Vec<f64>>, upper_errors: &Vec<Vec<f64>>, start_zero: bool, x_axis: bool) -> ((f64, f64), f64) {
     check_outliers(data, x_axis);
-    calc_data_range(data, start_zero, 0.8, 0.08, 0.2)
+
+    let mut extents = data.clone();
+    for j in 0..data.len() {
+        extents.push(data[j].iter().enumerate().map(|(i, &x)| x - get_error(lower_errors, j, i)).collect());
+        extents.push(data[j].iter().enumerate().map(|(i, &x)| x + get_error(upper_errors, j, i)).collect());
+    }
+    calc_data_range(&extents, start_zero, 0.8, 0.08, 0.2)
+}
+
+// Gets the size of the error of the ith value in the jth series, 0 if no error is given.
+pub(in chart_builder) fn get_error(errors: &Vec<Vec<f64>>, j: usize, i: usize) -> f64 {
+    errors.get(j).and_then(|series| series.get(i)).map(|e| e.abs()).unwrap_or(0.0)
 }
 
 
@@ -10,6 +10,7 @@ use chart_builder::charts::*;
 pub struct AreaChart {
     data_labels: Vec<String>,
     data: Vec<Vec<f64>>,
+    step_mode: StepMode,
     pub chart_prop: ChartProp,
     pub axis_prop: AxisProp,
 }
@@ -37,10 +38,18 @@ impl AreaChart {
         AreaChart {
             data_labels: new_data_labels,
             data: new_data,
+            step_mode: StepMode::Linear,
             chart_prop: ChartProp::new(chart_title, &axis_type),
             axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
         }
     }
+    /// Sets how the points of each area are joined (StepMode::Linear by default).
+    ///
+    /// ```step_mode``` is a StepMode value, use StepMode::Pre, StepMode::Post or StepMode::Mid
+    /// to fill under steps between points.
+    pub fn set_step_mode(&mut self, step_mode: StepMode) {
+        self.step_mode = step_mode;
+    }
     pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
         let data_labels = self.data_labels.clone();
         let data_y = self.data.clone();
@@ -48,6 +57,8 @@ impl AreaChart {
 
         let chart_title = self.chart_prop.chart_title.clone();
 
+        let step_mode = self.step_mode;
+
         let x_axis_title = self.axis_prop.x_axis_title.clone();
         let x_axis_scale = self.axis_prop.x_axis_scale;
 
@@ -102,14 +113,24 @@ impl AreaChart {
 
                 cr.move_to(_left_bound, intercept);
 
+                let mut prev_x: f64 = 0.0;
+                let mut prev_y: f64 = 0.0;
+
                 for i in 0..data_labels.len() {
                     let y_val = data_y[j][i];
                     let x = _left_bound + x_delimiter_interval * (i as f64);
                     let y = _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
 
-                    // draw lines from previous coordinate to current
+                    // draw lines from previous coordinate to current, through any steps between them
+                    if i != 0 {
+                        for point in get_step_points((prev_x, prev_y), (x, y), step_mode) {
+                            cr.line_to(point.0, point.1);
+                        }
+                    }
                     cr.line_to(x, y);
 
+                    prev_x = x;
+                    prev_y = y;
                 }
                 // closes off area to fill shape
                 cr.line_to(_left_bound + x_delimiter_interval * ((data_labels.len() - 1) as f64), intercept);
@@ -125,7 +146,7 @@ impl AreaChart {
             draw_x_axis_cat(cr, scalings,
                 &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
                 screen_size,
-                true);
+                true, 1);
             draw_y_axis_con(cr, scalings,
                 y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
                 screen_size);
@@ -0,0 +1,301 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Bullet Chart.
+///
+/// Shows measures against a target and qualitative ranges (such as poor, satisfactory and good) in a compact row for each measure.
+#[derive(Clone)]
+pub struct BulletChart {
+    data_labels: Vec<String>,
+    data_actual: Vec<f64>,
+    data_target: Vec<f64>,
+    data_ranges: Vec<Vec<f64>>,
+    vertical: bool,
+    shared_scale: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl BulletChart {
+    /// Creates a new instance of a BulletChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` specifies the label of each measure, placed to the left of its row.
+    ///
+    /// ```new_data_actual``` is the number data for the actual value of each measure, drawn as a bar.
+    ///
+    /// ```new_data_target``` is the number data for the target of each measure, drawn as a tick across the bar.
+    ///
+    /// ```new_data_ranges``` is the number data for the upper end of each qualitative range of each measure in increasing order,
+    /// drawn as bands from dark to light behind the bar.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data_actual: Vec<f64>, new_data_target: Vec<f64>, new_data_ranges: Vec<Vec<f64>>) -> BulletChart {
+        // the value axis is used for the x-axis of horizontal rows and the y-axis of vertical columns
+        let axis_props = calc_axis_props(&get_measure_values(&new_data_actual, &new_data_target, &new_data_ranges, None), true, true);
+        let axis_bounds = axis_props.0;
+        let axis_scale = axis_props.1;
+
+        let axis_type: AxisType =
+            if axis_bounds.0 < 0.0 && axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else { AxisType::Single };
+
+        BulletChart {
+            data_labels: new_data_labels,
+            data_actual: new_data_actual,
+            data_target: new_data_target,
+            data_ranges: new_data_ranges,
+            vertical: false,
+            shared_scale: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(axis_bounds, axis_bounds, axis_scale, axis_scale),
+        }
+    }
+    /// Set if measures should be drawn as vertical columns labelled below, in place of horizontal rows (false by default).
+    ///
+    /// ```vertical``` is a boolean value that should be set to true to draw vertical columns.
+    pub fn set_vertical(&mut self, vertical: bool) {
+        self.vertical = vertical;
+    }
+    /// Set if all measures should share one common scale, in place of each measure having its own (false by default).
+    ///
+    /// ```shared_scale``` is a boolean value that should be set to true to share a scale, using the bounds of the x-axis
+    /// for horizontal rows or the y-axis for vertical columns.
+    pub fn set_shared_scale(&mut self, shared_scale: bool) {
+        self.shared_scale = shared_scale;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let data_actual = self.data_actual.clone();
+        let data_target = self.data_target.clone();
+        let data_ranges = self.data_ranges.clone();
+        let vertical = self.vertical;
+        let shared_scale = self.shared_scale;
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let axis_title = if vertical == true { self.axis_prop.y_axis_title.clone() } else { self.axis_prop.x_axis_title.clone() };
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // ((min, max), scale) of each measure
+        let num_measures = data_labels.len();
+        let mut axis_props: Vec<((f64, f64), f64)> = Vec::new();
+        for i in 0..num_measures {
+            if shared_scale == true {
+                if vertical == true {
+                    axis_props.push((self.axis_prop.y_axis_bounds, self.axis_prop.y_axis_scale));
+                } else {
+                    axis_props.push((self.axis_prop.x_axis_bounds, self.axis_prop.x_axis_scale));
+                }
+            } else {
+                axis_props.push(calc_axis_props(&get_measure_values(&data_actual, &data_target, &data_ranges, Some(i)), true, !vertical));
+            }
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Bullet chart components
+
+            // space kept for labels to the left of rows, or below columns
+            let label_space = if vertical == true { 0.08 * _vertical_scaling } else { 0.2 * _horizontal_scaling };
+            // each measure has a slot across the rows or columns, with space after the bands for its own axis
+            let slot_size = if vertical == true { _horizontal_scaling } else { _vertical_scaling } / (num_measures.max(1) as f64);
+            let band_size = slot_size * if shared_scale == true { 0.6 } else { 0.4 };
+            let band_offset = if shared_scale == true { slot_size * 0.2 } else if vertical == true { slot_size * 0.45 } else { slot_size * 0.15 };
+
+            // scalings of the bands of each measure, in the same form as the scalings of the whole chart
+            let get_measure_scalings = |i: usize| {
+                if vertical == true {
+                    let left = _left_bound + slot_size * (i as f64) + band_offset;
+                    let lower = _lower_bound - label_space;
+                    (band_size, _vertical_scaling - label_space, left, left + band_size, lower, _upper_bound)
+                } else {
+                    let upper = _upper_bound + slot_size * (i as f64) + band_offset;
+                    let left = _left_bound + label_space;
+                    (_horizontal_scaling - label_space, band_size, left, _right_bound, upper + band_size, upper)
+                }
+            };
+
+            for i in 0..num_measures {
+                let measure_scalings = get_measure_scalings(i);
+                let left = measure_scalings.2;
+                let right = measure_scalings.3;
+                let lower = measure_scalings.4;
+                let upper = measure_scalings.5;
+                let axis_min = (axis_props[i].0).0;
+                let axis_max = (axis_props[i].0).1;
+
+                // rectangle between two values on the scale, across a proportion of the thickness of the bands
+                let draw_section = |start: f64, end: f64, thickness: f64| {
+                    let start = get_percentage_in_bounds(start.max(axis_min).min(axis_max), axis_min, axis_max);
+                    let end = get_percentage_in_bounds(end.max(axis_min).min(axis_max), axis_min, axis_max);
+                    if vertical == true {
+                        let inset = (right - left) * (1.0 - thickness) / 2.0;
+                        cr.rectangle(left + inset, lower - end * (lower - upper), (right - left) - inset * 2.0, (end - start) * (lower - upper));
+                    } else {
+                        let inset = (lower - upper) * (1.0 - thickness) / 2.0;
+                        cr.rectangle(left + start * (right - left), upper + inset, (end - start) * (right - left), (lower - upper) - inset * 2.0);
+                    }
+                    cr.fill();
+                };
+
+                // qualitative ranges from dark to light
+                let ranges = data_ranges.get(i).cloned().unwrap_or(Vec::new());
+                let mut range_start = axis_min;
+                for k in 0..ranges.len() {
+                    let shade = 0.55 + 0.35 * (k as f64) / ((ranges.len().max(2) - 1) as f64);
+                    cr.set_source_rgb(shade, shade, shade);
+                    draw_section(range_start, ranges[k], 1.0);
+                    range_start = ranges[k];
+                }
+
+                // actual value bar from zero (or the start of the scale)
+                if let Some(&actual) = data_actual.get(i) {
+                    let base = 0.0f64.max(axis_min).min(axis_max);
+                    set_nth_colour(cr, 0);
+                    draw_section(base.min(actual), base.max(actual), 0.35);
+                }
+
+                // target tick across the bar
+                if let Some(&target) = data_target.get(i) {
+                    if target >= axis_min && target <= axis_max {
+                        let pos = get_percentage_in_bounds(target, axis_min, axis_max);
+                        cr.set_source_rgb(0.0, 0.0, 0.0);
+                        if vertical == true {
+                            let y = lower - pos * (lower - upper);
+                            let inset = (right - left) * 0.15;
+                            cr.set_line_width(0.006 * v_scale);
+                            cr.move_to(left + inset, y);
+                            cr.line_to(right - inset, y);
+                        } else {
+                            let x = left + pos * (right - left);
+                            let inset = (lower - upper) * 0.15;
+                            cr.set_line_width(0.006 * h_scale);
+                            cr.move_to(x, upper + inset);
+                            cr.line_to(x, lower - inset);
+                        }
+                        cr.stroke();
+                    }
+                }
+
+                // measure label to the left of the row, or below the column
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.set_font_size(0.02);
+                let mut font_matrix = cr.get_font_matrix();
+                font_matrix.scale(h_scale, v_scale);
+                cr.set_font_matrix(font_matrix);
+                let label = data_labels[i].as_str();
+                let text_width = cr.text_extents(label).width;
+                let text_height = cr.text_extents(label).height;
+                if vertical == true {
+                    cr.move_to((left + right) / 2.0 - text_width / 2.0, _lower_bound - label_space / 2.0 + text_height / 2.0);
+                } else {
+                    cr.move_to(left - 0.015 * h_scale - text_width, (upper + lower) / 2.0 + text_height / 2.0);
+                }
+                cr.show_text(label);
+
+                // own axis for each measure
+                if shared_scale == false {
+                    if vertical == true {
+                        draw_y_axis_con(cr, measure_scalings,
+                            axis_min, axis_max, (axis_props[i]).1, 0.0, &String::new(),
+                            screen_size);
+                    } else {
+                        draw_x_axis_con(cr, measure_scalings,
+                            axis_min, axis_max, (axis_props[i]).1, 0.0, &String::new(),
+                            screen_size);
+                    }
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis shared by all measures
+            if shared_scale == true && num_measures > 0 {
+                let axis_min = (axis_props[0].0).0;
+                let axis_max = (axis_props[0].0).1;
+                if vertical == true {
+                    let axis_scalings = (_horizontal_scaling, _vertical_scaling - label_space, _left_bound, _right_bound, _lower_bound - label_space, _upper_bound);
+                    draw_y_axis_con(cr, axis_scalings,
+                        axis_min, axis_max, axis_props[0].1, 0.0, &axis_title,
+                        screen_size);
+                } else {
+                    let axis_scalings = (_horizontal_scaling - label_space, _vertical_scaling, _left_bound + label_space, _right_bound, _lower_bound, _upper_bound);
+                    draw_x_axis_con(cr, axis_scalings,
+                        axis_min, axis_max, axis_props[0].1, 0.0, &axis_title,
+                        screen_size);
+                }
+            }
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for BulletChart {
+    fn draw(&self) {
+        build_window(ChartType::Bullet(self.clone()));
+    }
+}
+
+/*
+ * Helper function
+ */
+
+// Actual, target and range values of one measure (or all measures if None), used to calculate the scale.
+fn get_measure_values(data_actual: &Vec<f64>, data_target: &Vec<f64>, data_ranges: &Vec<Vec<f64>>, measure: Option<usize>) -> Vec<Vec<f64>> {
+    let mut values: Vec<f64> = Vec::new();
+    let num_measures = data_actual.len().max(data_target.len()).max(data_ranges.len());
+    for i in 0..num_measures {
+        if measure.is_some() && measure != Some(i) {
+            continue;
+        }
+        values.extend(data_actual.get(i));
+        values.extend(data_target.get(i));
+        if let Some(ranges) = data_ranges.get(i) {
+            values.extend(ranges.iter());
+        }
+    }
+    vec![values]
+}
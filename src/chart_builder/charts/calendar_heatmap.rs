@@ -0,0 +1,198 @@
+//!
+
+
+use chart_builder::charts::*;
+use data_parser;
+use std::collections::HashMap;
+
+/// Structure used for storing chart related data and the drawing of a Calendar Heatmap.
+///
+/// Shows a value for each day of one or more years, with a column for each week and a row for each day of the week,
+/// revealing weekly and seasonal patterns such as daily activity.
+#[derive(Clone)]
+pub struct CalendarHeatmap {
+    data_days: Vec<f64>,
+    data_values: Vec<f64>,
+    pub chart_prop: ChartProp,
+}
+
+impl CalendarHeatmap {
+    /// Creates a new instance of a CalendarHeatmap.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_dates``` are the dates of each value in the format ```YYYY-MM-DD```, values with the same date are added together.
+    ///
+    /// ```new_values``` is the number data for each date, with indexes corresponding to the same index in new_dates.
+    ///
+    /// Each year from the first to the last date is drawn as a separate panel, stacked from the top.
+    /// A colour bar is shown in the legend section of the window by default, this can be hidden using chart_prop.set_show_legend(false).
+    pub fn new(chart_title: String, new_dates: Vec<String>, new_values: Vec<f64>) -> CalendarHeatmap {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        let mut chart_prop = ChartProp::new(chart_title, &axis_type);
+        chart_prop.set_show_legend(true);
+
+        CalendarHeatmap {
+            data_days: data_parser::vec_date_to_num(&new_dates),
+            data_values: new_values,
+            chart_prop: chart_prop,
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        // Actual size of screen generate if legend section (colour bar) is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // total of the values on each day
+        let mut day_values: HashMap<i64, f64> = HashMap::new();
+        for i in 0..self.data_days.len().min(self.data_values.len()) {
+            *day_values.entry(self.data_days[i] as i64).or_insert(0.0) += self.data_values[i];
+        }
+        let colour_min = day_values.values().fold(0./0., |cur_min: f64, &x| cur_min.min(x));
+        let colour_max = day_values.values().fold(0./0., |cur_max: f64, &x| cur_max.max(x));
+
+        let get_year = |day: f64| data_parser::num_to_date(day)[0..4].parse::<i64>().unwrap_or(1970);
+        let first_year = self.data_days.iter().fold(1.0 / 0.0, |cur_min: f64, &x| cur_min.min(x));
+        let last_year = self.data_days.iter().fold(- 1.0 / 0.0, |cur_max: f64, &x| cur_max.max(x));
+        let years: Vec<i64> = if first_year.is_finite() { (get_year(first_year)..(get_year(last_year) + 1)).collect() } else { Vec::new() };
+
+        // (week column, weekday row, value) of every day of each year, and the first column of each month
+        let mut panels: Vec<Vec<(usize, usize, Option<f64>)>> = Vec::new();
+        let mut month_columns: Vec<Vec<usize>> = Vec::new();
+        for &year in years.iter() {
+            let first_day = data_parser::date_to_num(&format!("{}-01-01", year)) as i64;
+            let last_day = data_parser::date_to_num(&format!("{}-12-31", year)) as i64;
+            // weeks start on monday, 1970-01-01 was a thursday
+            let get_weekday = |day: i64| ((day + 3) % 7 + 7) % 7;
+            let first_monday = first_day - get_weekday(first_day);
+
+            let mut days: Vec<(usize, usize, Option<f64>)> = Vec::new();
+            for day in first_day..(last_day + 1) {
+                days.push((((day - first_monday) / 7) as usize, get_weekday(day) as usize, day_values.get(&day).cloned()));
+            }
+            panels.push(days);
+
+            month_columns.push((1..13).map(|month| {
+                let day = data_parser::date_to_num(&format!("{}-{:02}-01", year, month)) as i64;
+                ((day - first_monday) / 7) as usize
+            }).collect());
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Calendar Heatmap components
+
+            // space on the left for year and weekday labels
+            let label_space = 0.08 * _horizontal_scaling;
+            let grid_left = _left_bound + label_space;
+
+            // square days, fitting 54 weeks across and 7 days (and a row of month labels) in each panel
+            let num_panels = panels.len().max(1) as f64;
+            let cell_size = ((_horizontal_scaling - label_space) * screen_size.0 / 54.0)
+                .min(_vertical_scaling * screen_size.1 / num_panels / 9.0);
+            let cell_width = cell_size / screen_size.0;
+            let cell_height = cell_size / screen_size.1;
+            let panel_height = cell_height * 9.0;
+            // panels are centered vertically
+            let panels_top = _upper_bound + (_vertical_scaling - panel_height * num_panels) / 2.0;
+
+            let month_names = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+            let weekday_names = ["Mon", "", "Wed", "", "Fri", "", ""];
+
+            for p in 0..panels.len() {
+                let grid_top = panels_top + panel_height * (p as f64) + cell_height * 1.5;
+
+                // each day, with a small gap between them
+                for &(col, row, value) in panels[p].iter() {
+                    match value {
+                        Some(value) => set_scale_colour(cr, get_percentage_in_bounds(value, colour_min, colour_max)),
+                        None => cr.set_source_rgb(0.92, 0.92, 0.92),
+                    }
+                    cr.rectangle(grid_left + cell_width * (col as f64) + cell_width * 0.1, grid_top + cell_height * (row as f64) + cell_height * 0.1,
+                        cell_width * 0.8, cell_height * 0.8);
+                    cr.fill();
+                }
+
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.set_font_size(0.016);
+                let mut font_matrix = cr.get_font_matrix();
+                font_matrix.scale(h_scale, v_scale);
+                cr.set_font_matrix(font_matrix);
+
+                // month labels above the first week of each month
+                for m in 0..month_columns[p].len() {
+                    cr.move_to(grid_left + cell_width * (month_columns[p][m] as f64), grid_top - cell_height * 0.4);
+                    cr.show_text(month_names[m]);
+                }
+
+                // weekday labels to the left of the rows
+                for row in 0..weekday_names.len() {
+                    let text_width = cr.text_extents(weekday_names[row]).width;
+                    let text_height = cr.text_extents(weekday_names[row]).height;
+                    cr.move_to(grid_left - text_width - 0.005 * h_scale, grid_top + cell_height * ((row as f64) + 0.5) + text_height / 2.0);
+                    cr.show_text(weekday_names[row]);
+                }
+
+                // year label down the left of the panel
+                let year_str = years[p].to_string();
+                font_matrix.scale(1.2, 1.2);
+                font_matrix.rotate(- 3.14159265359 * 0.5);
+                cr.set_font_matrix(font_matrix);
+                let text_width = cr.text_extents(year_str.as_str()).width;
+                let text_height = cr.text_extents(year_str.as_str()).height;
+                cr.move_to(_left_bound + text_width, grid_top + cell_height * 3.5 + text_height / 2.0);
+                cr.show_text(year_str.as_str());
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw colour bar if chosen
+            if show_legend == true && colour_min.is_finite() {
+                draw_colour_bar(cr, colour_min, colour_max, 0, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for CalendarHeatmap {
+    fn draw(&self) {
+        build_window(ChartType::Calendar(self.clone()));
+    }
+}
@@ -0,0 +1,349 @@
+//!
+
+
+use chart_builder::charts::*;
+use data_parser;
+
+/// Structure used for storing chart related data and the drawing of a Candlestick Chart.
+///
+/// Shows the open, high, low and close prices of a financial series for each period (e.g. each day).
+/// Periods are either evenly spaced categories on the x-axis, or placed at their dates on a time scale.
+#[derive(Clone)]
+pub struct CandlestickChart {
+    data_labels: Vec<String>,
+    data_dates: Vec<f64>,
+    data_open: Vec<f64>,
+    data_high: Vec<f64>,
+    data_low: Vec<f64>,
+    data_close: Vec<f64>,
+    data_volume: Vec<f64>,
+    ohlc_bars: bool,
+    up_colour: (f64, f64, f64),
+    down_colour: (f64, f64, f64),
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl CandlestickChart {
+    /// Creates a new instance of a CandlestickChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` is the string data (e.g. dates) placed on the x-axis of the chart, each for a period.
+    ///
+    /// ```new_data_open```, ```new_data_high```, ```new_data_low``` and ```new_data_close``` are the number data
+    /// for each period, with indexes corresponding to the same index in new_data_labels.
+    ///
+    /// Panics if the labels and the open, high, low and close data do not all have the same number of elements.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data_open: Vec<f64>, new_data_high: Vec<f64>, new_data_low: Vec<f64>, new_data_close: Vec<f64>) -> CandlestickChart {
+        let num_periods = new_data_labels.len();
+        if new_data_open.len() != num_periods || new_data_high.len() != num_periods || new_data_low.len() != num_periods || new_data_close.len() != num_periods {
+            panic!("The labels and the open, high, low and close data must have the same number of elements");
+        }
+
+        let x_axis_bounds = (0.0, 0.0);
+        let x_axis_scale = 1.0 / (new_data_labels.len() as f64);
+        let y_axis_props = calc_axis_props(&vec![new_data_low.clone(), new_data_high.clone()], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        CandlestickChart {
+            data_labels: new_data_labels,
+            data_dates: Vec::new(),
+            data_open: new_data_open,
+            data_high: new_data_high,
+            data_low: new_data_low,
+            data_close: new_data_close,
+            data_volume: Vec::new(),
+            ohlc_bars: false,
+            up_colour: COLOURS[1],
+            down_colour: COLOURS[0],
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Creates a new instance of a CandlestickChart where each period is placed at its date on a time scale x-axis,
+    /// so gaps between dates (e.g. weekends and holidays) are shown.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_dates``` is the date (in the format ```YYYY-MM-DD```) of each period.
+    ///
+    /// ```new_data_open```, ```new_data_high```, ```new_data_low``` and ```new_data_close``` are the number data
+    /// for each period, with indexes corresponding to the same index in new_dates.
+    ///
+    /// Panics if the dates and the open, high, low and close data do not all have the same number of elements.
+    pub fn from_dates(chart_title: String, new_dates: Vec<String>, new_data_open: Vec<f64>, new_data_high: Vec<f64>, new_data_low: Vec<f64>, new_data_close: Vec<f64>) -> CandlestickChart {
+        let data_dates = data_parser::vec_date_to_num(&new_dates);
+        let mut candlestick_chart = CandlestickChart::new(chart_title, new_dates, new_data_open, new_data_high, new_data_low, new_data_close);
+
+        // axis includes half of the smallest gap either side of the first and last dates so no candle is clipped
+        let half_gap = vec![vec![calc_min_gap(&data_dates) / 2.0; data_dates.len()]];
+        let x_axis_props = calc_error_axis_props(&vec![data_dates.clone()], &half_gap, &half_gap, false, true);
+        candlestick_chart.axis_prop.x_axis_bounds = x_axis_props.0;
+        // dates are wider than numbers so fewer fit on the axis
+        candlestick_chart.axis_prop.x_axis_scale = x_axis_props.1.max(0.2);
+        candlestick_chart.data_dates = data_dates;
+        candlestick_chart
+    }
+    /// Creates a new instance of a CandlestickChart using columns of a CSV file.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```label_col```, ```open_col```, ```high_col```, ```low_col``` and ```close_col``` specify the columns
+    /// of the CSV file containing the labels (e.g. dates), open, high, low and close data.
+    ///
+    /// ```start``` specifies the position of the first element in the columns to be read.
+    ///
+    /// ```end``` specifies the position of the last element in the columns to be read.
+    ///
+    /// ```file_name``` specifies the CSV file to read data from.
+    pub fn from_csv(chart_title: String, label_col: usize, open_col: usize, high_col: usize, low_col: usize, close_col: usize,
+            start: usize, end: usize, file_name: &str) -> CandlestickChart {
+        CandlestickChart::new(chart_title,
+            data_parser::get_str_col(label_col, start, end, file_name),
+            data_parser::get_num_col(open_col, start, end, file_name),
+            data_parser::get_num_col(high_col, start, end, file_name),
+            data_parser::get_num_col(low_col, start, end, file_name),
+            data_parser::get_num_col(close_col, start, end, file_name))
+    }
+    /// Sets the volume traded in each period, shown as bars in a panel below the prices.
+    ///
+    /// ```new_data_volume``` is the number data for each period, with indexes corresponding to the same index in the price data.
+    ///
+    /// No volume panel is shown by default.
+    pub fn set_volume(&mut self, new_data_volume: Vec<f64>) {
+        self.data_volume = new_data_volume;
+    }
+    /// Set if OHLC bars should be drawn in place of candlesticks (false by default).
+    ///
+    /// ```ohlc_bars``` is a boolean value that should be set to true to draw OHLC bars,
+    /// with a tick to the left for the open price and to the right for the close price.
+    pub fn set_ohlc_bars(&mut self, ohlc_bars: bool) {
+        self.ohlc_bars = ohlc_bars;
+    }
+    /// Sets the colour of periods where the close price is at or above the open price (green by default).
+    ///
+    /// ```red```, ```green``` and ```blue``` are values between 0.0 and 1.0.
+    pub fn set_up_colour(&mut self, red: f64, green: f64, blue: f64) {
+        self.up_colour = (red, green, blue);
+    }
+    /// Sets the colour of periods where the close price is below the open price (red by default).
+    ///
+    /// ```red```, ```green``` and ```blue``` are values between 0.0 and 1.0.
+    pub fn set_down_colour(&mut self, red: f64, green: f64, blue: f64) {
+        self.down_colour = (red, green, blue);
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let data_dates = self.data_dates.clone();
+        let date_axis = data_dates.len() > 0;
+        let data_open = self.data_open.clone();
+        let data_high = self.data_high.clone();
+        let data_low = self.data_low.clone();
+        let data_close = self.data_close.clone();
+        let data_volume = self.data_volume.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let ohlc_bars = self.ohlc_bars;
+        let up_colour = self.up_colour;
+        let down_colour = self.down_colour;
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+        let min_gap = calc_min_gap(&data_dates);
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        let screen_size = self.chart_prop.screen_size;
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        scalings = get_normal_scale();
+
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // Split drawing area into a price panel and a volume panel if volume is shown
+        let show_volume = data_volume.len() > 0;
+        let price_scalings: (f64, f64, f64, f64 ,f64, f64);
+        let volume_scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_volume == true {
+            price_scalings = (_horizontal_scaling, _vertical_scaling * 0.68, _left_bound, _right_bound, _upper_bound + _vertical_scaling * 0.68, _upper_bound);
+            volume_scalings = (_horizontal_scaling, _vertical_scaling * 0.24, _left_bound, _right_bound, _lower_bound, _lower_bound - _vertical_scaling * 0.24);
+        } else {
+            price_scalings = scalings;
+            volume_scalings = scalings;
+        }
+
+        // generate volume axis properties from volume data
+        let volume_axis_props = if show_volume == true { calc_axis_props(&vec![data_volume.clone()], true, false) } else { ((0.0, 1.0), 1.0) };
+        let volume_axis_min = (volume_axis_props.0).0;
+        let volume_axis_max = (volume_axis_props.0).1;
+        // fewer delimiters fit in the smaller volume panel
+        let volume_axis_scale = volume_axis_props.1.max(0.25);
+
+        // only label as many periods as will fit on the x-axis
+        let label_interval = ((data_labels.len() as f64) / 12.0).ceil().max(1.0) as usize;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Candlestick chart components
+            let _vertical_scaling = price_scalings.1;
+            let _lower_bound = price_scalings.4;
+
+            // categories are evenly spaced, dates are placed on a time scale with the width of the smallest gap between them
+            let x_delimiter_interval: f64 = _horizontal_scaling * x_axis_scale;
+            let body_width = if date_axis == true { min_gap / (x_axis_max - x_axis_min) * _horizontal_scaling * 0.6 } else { x_delimiter_interval * 0.6 };
+            let get_x = |i: usize| if date_axis == true {
+                _left_bound + (get_percentage_in_bounds(data_dates[i], x_axis_min, x_axis_max) * _horizontal_scaling)
+            } else {
+                _left_bound - (x_delimiter_interval / 2.0) + x_delimiter_interval * ((i + 1) as f64)
+            };
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            for i in 0..data_labels.len() {
+                let x = get_x(i);
+
+                let colour = if data_close[i] >= data_open[i] { up_colour } else { down_colour };
+                cr.set_source_rgb(colour.0, colour.1, colour.2);
+
+                // line from high to low
+                cr.set_line_width(0.002 * h_scale);
+                cr.move_to(x, get_y(data_high[i]));
+                cr.line_to(x, get_y(data_low[i]));
+                cr.stroke();
+
+                if ohlc_bars == true {
+                    // open tick to the left and close tick to the right
+                    cr.set_line_width(0.002 * v_scale);
+                    cr.move_to(x - body_width / 2.0, get_y(data_open[i]));
+                    cr.line_to(x, get_y(data_open[i]));
+                    cr.move_to(x, get_y(data_close[i]));
+                    cr.line_to(x + body_width / 2.0, get_y(data_close[i]));
+                    cr.stroke();
+                } else {
+                    // body from open to close, always at least visible as a line
+                    let top = get_y(data_open[i].max(data_close[i]));
+                    let height = (get_y(data_open[i].min(data_close[i])) - top).max(0.002 * v_scale);
+                    cr.rectangle(x - body_width / 2.0, top, body_width, height);
+                    cr.fill();
+                }
+            }
+
+            // Draw volume bars coloured by whether the price rose or fell
+            if show_volume == true {
+                let _vertical_scaling = volume_scalings.1;
+                let _lower_bound = volume_scalings.4;
+                // volumes without a matching period of price data are not shown
+                for i in 0..data_volume.len().min(data_labels.len()) {
+                    let x = get_x(i);
+                    let colour = if data_close[i] >= data_open[i] { up_colour } else { down_colour };
+                    cr.set_source_rgba(colour.0, colour.1, colour.2, 0.7);
+                    cr.rectangle(
+                        x - body_width / 2.0,
+                        _lower_bound,
+                        body_width,
+                        - get_percentage_in_bounds(data_volume[i], volume_axis_min, volume_axis_max) * _vertical_scaling);
+                    cr.fill();
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            if date_axis == true {
+                if show_volume == true {
+                    // dates are labelled under the volume panel, the price panel only has its axis line
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                    cr.set_line_width(0.002 * v_scale);
+                    cr.move_to(_left_bound, price_scalings.4);
+                    cr.rel_line_to(_horizontal_scaling, 0.0);
+                    cr.stroke();
+                    draw_x_axis_date(cr, volume_scalings,
+                        x_axis_min, x_axis_max, x_axis_scale, 0.0, &x_axis_title,
+                        screen_size);
+                    draw_y_axis_con(cr, volume_scalings,
+                        volume_axis_min, volume_axis_max, volume_axis_scale, 0.0, &String::from("Volume"),
+                        screen_size);
+                } else {
+                    draw_x_axis_date(cr, price_scalings,
+                        x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                        screen_size);
+                }
+            } else if show_volume == true {
+                // x-axis labels are drawn under the volume panel
+                let empty_labels: Vec<String> = data_labels.iter().map(|_| String::new()).collect();
+                draw_x_axis_cat(cr, price_scalings,
+                    &empty_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &String::new(),
+                    screen_size,
+                    false, label_interval);
+                draw_x_axis_cat(cr, volume_scalings,
+                    &data_labels, x_axis_scale, 0.0, &x_axis_title,
+                    screen_size,
+                    false, label_interval);
+                draw_y_axis_con(cr, volume_scalings,
+                    volume_axis_min, volume_axis_max, volume_axis_scale, 0.0, &String::from("Volume"),
+                    screen_size);
+            } else {
+                draw_x_axis_cat(cr, price_scalings,
+                    &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                    screen_size,
+                    false, label_interval);
+            }
+            draw_y_axis_con(cr, price_scalings,
+                y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
+                screen_size);
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for CandlestickChart {
+    fn draw(&self) {
+        build_window(ChartType::Candlestick(self.clone()));
+    }
+}
+
+// Smallest gap between consecutive dates, 1 day if there are fewer than two distinct dates.
+fn calc_min_gap(data_dates: &Vec<f64>) -> f64 {
+    let mut sorted_dates = data_dates.clone();
+    sorted_dates.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let min_gap = (1..sorted_dates.len()).fold(0./0., |cur_min: f64, i| {
+        let gap = sorted_dates[i] - sorted_dates[i - 1];
+        if gap > 0.0 { cur_min.min(gap) } else { cur_min }
+    });
+    if min_gap.is_finite() { min_gap } else { 1.0 }
+}
@@ -0,0 +1,399 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Contour Plot.
+///
+/// Shows a 2D scalar field sampled on a grid with lines (or filled bands) joining points of equal value.
+#[derive(Clone)]
+pub struct ContourPlot {
+    data_x: Vec<f64>,
+    data_y: Vec<f64>,
+    data_z: Vec<Vec<f64>>,
+    levels: Vec<f64>,
+    filled: bool,
+    show_labels: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl ContourPlot {
+    /// Creates a new instance of a ContourPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_x``` is the x coordinate of each column of the grid, in increasing order.
+    ///
+    /// ```new_data_y``` is the y coordinate of each row of the grid, in increasing order.
+    ///
+    /// ```new_data_z``` is the value of the field at each point of the grid,
+    /// where ```new_data_z[j][i]``` is the value at (```new_data_x[i]```, ```new_data_y[j]```).
+    ///
+    /// A colour bar is shown in the legend section of the window by default, this can be hidden using chart_prop.set_show_legend(false).
+    ///
+    /// Panics if the grid is empty, if ```new_data_z``` does not have a row for each y coordinate, each with a value for each x coordinate,
+    /// or if none of the values of the field are finite.
+    pub fn new(chart_title: String, new_data_x: Vec<f64>, new_data_y: Vec<f64>, new_data_z: Vec<Vec<f64>>) -> ContourPlot {
+        if new_data_x.len() == 0 || new_data_y.len() == 0 {
+            panic!("The grid must have at least one x and one y coordinate");
+        }
+        if new_data_z.len() != new_data_y.len() || new_data_z.iter().any(|row| row.len() != new_data_x.len()) {
+            panic!("The z data must have a row for each y coordinate, each with a value for each x coordinate");
+        }
+        if !new_data_z.iter().any(|row| row.iter().any(|z| z.is_finite())) {
+            panic!("The z data must contain at least one finite value");
+        }
+
+        let x_axis_props = calc_axis_props(&vec![new_data_x.clone()], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![new_data_y.clone()], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        let levels = calc_contour_levels(&new_data_z);
+
+        let mut chart_prop = ChartProp::new(chart_title, &axis_type);
+        chart_prop.set_show_legend(true);
+
+        ContourPlot {
+            data_x: new_data_x,
+            data_y: new_data_y,
+            data_z: new_data_z,
+            levels: levels,
+            filled: false,
+            show_labels: false,
+            chart_prop: chart_prop,
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the values at which contour lines are drawn.
+    ///
+    /// ```new_levels``` is a Vec<f64> of the values for each contour line, values that are not finite are ignored.
+    ///
+    /// Defaults are intelligently selected with use of the data provided.
+    pub fn set_levels(&mut self, new_levels: Vec<f64>) {
+        let mut levels: Vec<f64> = new_levels.into_iter().filter(|level| level.is_finite()).collect();
+        levels.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        levels.dedup();
+        self.levels = levels;
+    }
+    /// Set if the bands between contour lines should be filled with colour (false by default).
+    ///
+    /// ```filled``` is a boolean value that should be set to true to fill the bands between contour lines.
+    pub fn set_filled(&mut self, filled: bool) {
+        self.filled = filled;
+    }
+    /// Set if each contour line should be labelled with its value (false by default).
+    ///
+    /// ```show_labels``` is a boolean value that should be set to true to label the contour lines.
+    pub fn set_show_labels(&mut self, show_labels: bool) {
+        self.show_labels = show_labels;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_x = self.data_x.clone();
+        let data_y = self.data_y.clone();
+        let data_z = self.data_z.clone();
+        let levels = self.levels.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let filled = self.filled;
+        let show_labels = self.show_labels;
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        // Actual size of screen generate if legend section (colour bar) is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // range of values in the field, ignoring gaps (non finite values)
+        let z_min: f64 = data_z.iter().fold(-0./0., |vec_cur_min, ref x| vec_cur_min.min(x.iter().filter(|z| z.is_finite()).fold(-0./0., |cur_min, &x| cur_min.min(x))));
+        let z_max: f64 = data_z.iter().fold(0./0., |vec_cur_max, ref x| vec_cur_max.max(x.iter().filter(|z| z.is_finite()).fold(0./0., |cur_max, &x| cur_max.max(x))));
+
+        // iso-lines for each level
+        let mut contour_lines: Vec<Vec<((f64, f64), (f64, f64))>> = Vec::new();
+        for k in 0..levels.len() {
+            contour_lines.push(calc_contour_segments(&data_x, &data_y, &data_z, levels[k]));
+        }
+
+        // filled bands between each pair of levels, with the band below the first and above the last level
+        let mut band_bounds: Vec<f64> = vec![z_min.min(*levels.first().unwrap_or(&z_min))];
+        band_bounds.extend(levels.iter().cloned());
+        band_bounds.push(z_max.max(*levels.last().unwrap_or(&z_max)));
+        let mut bands: Vec<Vec<Vec<(f64, f64)>>> = Vec::new();
+        if filled == true {
+            for k in 0..(band_bounds.len() - 1) {
+                bands.push(calc_contour_band(&data_x, &data_y, &data_z, band_bounds[k], band_bounds[k + 1]));
+            }
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Contour plot components
+
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            // fill bands first so that lines are drawn on top
+            cr.set_line_width(0.001);
+            for k in 0..bands.len() {
+                let proportion = if bands.len() == 1 { 0.0 } else { (k as f64) / ((bands.len() - 1) as f64) };
+                set_scale_colour(cr, proportion);
+                for polygon in bands[k].iter() {
+                    cr.move_to(get_x(polygon[0].0), get_y(polygon[0].1));
+                    for i in 1..polygon.len() {
+                        cr.line_to(get_x(polygon[i].0), get_y(polygon[i].1));
+                    }
+                    cr.close_path();
+                    // stroke with the same colour hides seams between neighbouring grid cells
+                    cr.fill_preserve();
+                    cr.stroke();
+                }
+            }
+
+            // draw contour lines, black if over filled bands, otherwise coloured by level
+            cr.set_line_width(0.003);
+            cr.set_line_cap(cairo::LineCap::Round);
+            for k in 0..contour_lines.len() {
+                if filled == true {
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                } else {
+                    set_scale_colour(cr, get_percentage_in_bounds(levels[k], band_bounds[0], band_bounds[band_bounds.len() - 1]));
+                }
+                for &((x1, y1), (x2, y2)) in contour_lines[k].iter() {
+                    cr.move_to(get_x(x1), get_y(y1));
+                    cr.line_to(get_x(x2), get_y(y2));
+                }
+                cr.stroke();
+            }
+
+            // label each contour line near the middle of its segments
+            if show_labels == true {
+                cr.set_font_size(0.016);
+                let mut font_matrix = cr.get_font_matrix();
+                font_matrix.scale(h_scale, v_scale);
+                cr.set_font_matrix(font_matrix);
+
+                let dps: usize;
+                if z_max.abs().max(z_min.abs()) <= 0.1 { dps = 4; }
+                else if z_max.abs().max(z_min.abs()) >= 100.0 { dps = 0; }
+                else { dps = 2; }
+
+                for k in 0..contour_lines.len() {
+                    if contour_lines[k].len() == 0 { continue; }
+                    let ((x1, y1), (x2, y2)) = contour_lines[k][contour_lines[k].len() / 2];
+                    let x = (get_x(x1) + get_x(x2)) / 2.0;
+                    let y = (get_y(y1) + get_y(y2)) / 2.0;
+
+                    let label_string = format!("{:.*}", dps, levels[k]);
+                    let label_str = label_string.as_str();
+                    let text_width = cr.text_extents(label_str).width;
+                    let text_height = cr.text_extents(label_str).height;
+
+                    // white background so the label can be read over the line
+                    cr.set_source_rgb(1.0, 1.0, 1.0);
+                    cr.rectangle(x - text_width / 2.0 - 0.003, y - text_height / 2.0 - 0.003, text_width + 0.006, text_height + 0.006);
+                    cr.fill();
+
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                    cr.move_to(x - text_width / 2.0, y + text_height / 2.0);
+                    cr.show_text(label_str);
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw colour bar if chosen
+            if show_legend == true && z_min.is_finite() {
+                if filled == true {
+                    draw_banded_colour_bar(cr, &band_bounds, screen_size, legend_size);
+                } else {
+                    draw_colour_bar(cr, band_bounds[0], band_bounds[band_bounds.len() - 1], 0, screen_size, legend_size);
+                }
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for ContourPlot {
+    fn draw(&self) {
+        build_window(ChartType::Contour(self.clone()));
+    }
+}
+
+// Levels placed at each delimiter of a scale generated from the range of the field, excluding its ends.
+fn calc_contour_levels(data_z: &Vec<Vec<f64>>) -> Vec<f64> {
+    // rows left without any finite values are dropped as they have no range
+    let finite_z: Vec<Vec<f64>> = data_z.iter()
+        .map(|row| row.iter().cloned().filter(|z| z.is_finite()).collect::<Vec<f64>>())
+        .filter(|row| row.len() > 0)
+        .collect();
+    let z_props = calc_data_range(&finite_z, false, 0.8, 0.08, 0.2);
+    let z_min = (z_props.0).0;
+    let z_max = (z_props.0).1;
+    let interval = (z_max - z_min) * z_props.1;
+
+    let data_min: f64 = finite_z.iter().fold(-0./0., |vec_cur_min, ref x| vec_cur_min.min(x.iter().fold(-0./0., |cur_min, &x| cur_min.min(x))));
+    let data_max: f64 = finite_z.iter().fold(0./0., |vec_cur_max, ref x| vec_cur_max.max(x.iter().fold(0./0., |cur_max, &x| cur_max.max(x))));
+
+    let mut levels: Vec<f64> = Vec::new();
+    let num_delimiters = ((1.0 / z_props.1).round() as usize) + 1;
+    for i in 0..num_delimiters {
+        let level = format!("{:.*}", 12, z_min + interval * (i as f64)).parse::<f64>().unwrap();
+        if level > data_min && level < data_max {
+            levels.push(level);
+        }
+    }
+    levels
+}
+
+// Marching squares - returns the line segments (in data coordinates) where the field crosses level.
+fn calc_contour_segments(data_x: &Vec<f64>, data_y: &Vec<f64>, data_z: &Vec<Vec<f64>>, level: f64) -> Vec<((f64, f64), (f64, f64))> {
+    let mut segments: Vec<((f64, f64), (f64, f64))> = Vec::new();
+    // a grid needs at least one cell
+    if data_x.len() < 2 || data_y.len() < 2 { return segments; }
+
+    for j in 0..(data_y.len() - 1) {
+        for i in 0..(data_x.len() - 1) {
+            // corners of cell anticlockwise from bottom left
+            let corners = [
+                (data_x[i], data_y[j], data_z[j][i]),
+                (data_x[i + 1], data_y[j], data_z[j][i + 1]),
+                (data_x[i + 1], data_y[j + 1], data_z[j + 1][i + 1]),
+                (data_x[i], data_y[j + 1], data_z[j + 1][i]),
+            ];
+            // gaps in the field break the line
+            if corners.iter().any(|c| !c.2.is_finite()) { continue; }
+
+            // find point where level crosses each edge (bottom, right, top, left)
+            let mut crossings: Vec<(f64, f64)> = Vec::new();
+            for e in 0..4 {
+                let a = corners[e];
+                let b = corners[(e + 1) % 4];
+                if (a.2 >= level) != (b.2 >= level) {
+                    let d = (level - a.2) / (b.2 - a.2);
+                    crossings.push((a.0 + d * (b.0 - a.0), a.1 + d * (b.1 - a.1)));
+                }
+            }
+
+            if crossings.len() == 2 {
+                segments.push((crossings[0], crossings[1]));
+            } else if crossings.len() == 4 {
+                // saddle point - centre value decides which opposite corners are joined
+                let centre = (corners[0].2 + corners[1].2 + corners[2].2 + corners[3].2) / 4.0;
+                if (corners[0].2 >= level) == (centre >= level) {
+                    segments.push((crossings[0], crossings[1]));
+                    segments.push((crossings[2], crossings[3]));
+                } else {
+                    segments.push((crossings[3], crossings[0]));
+                    segments.push((crossings[1], crossings[2]));
+                }
+            }
+        }
+    }
+    segments
+}
+
+// Returns polygons (in data coordinates) covering the parts of each grid cell where lower <= value <= upper.
+fn calc_contour_band(data_x: &Vec<f64>, data_y: &Vec<f64>, data_z: &Vec<Vec<f64>>, lower: f64, upper: f64) -> Vec<Vec<(f64, f64)>> {
+    // Clips a polygon with a value at each point, keeping the part where the value is on the kept side of limit.
+    fn clip(polygon: &Vec<(f64, f64, f64)>, limit: f64, keep_above: bool) -> Vec<(f64, f64, f64)> {
+        let inside = |p: &(f64, f64, f64)| if keep_above { p.2 >= limit } else { p.2 <= limit };
+        let mut result: Vec<(f64, f64, f64)> = Vec::new();
+        for i in 0..polygon.len() {
+            let cur = polygon[i];
+            let prev = polygon[(i + polygon.len() - 1) % polygon.len()];
+            if inside(&cur) != inside(&prev) {
+                let d = (limit - prev.2) / (cur.2 - prev.2);
+                result.push((prev.0 + d * (cur.0 - prev.0), prev.1 + d * (cur.1 - prev.1), limit));
+            }
+            if inside(&cur) {
+                result.push(cur);
+            }
+        }
+        result
+    }
+
+    let mut polygons: Vec<Vec<(f64, f64)>> = Vec::new();
+    // a grid needs at least one cell
+    if data_x.len() < 2 || data_y.len() < 2 { return polygons; }
+
+    for j in 0..(data_y.len() - 1) {
+        for i in 0..(data_x.len() - 1) {
+            let cell = vec![
+                (data_x[i], data_y[j], data_z[j][i]),
+                (data_x[i + 1], data_y[j], data_z[j][i + 1]),
+                (data_x[i + 1], data_y[j + 1], data_z[j + 1][i + 1]),
+                (data_x[i], data_y[j + 1], data_z[j + 1][i]),
+            ];
+            if cell.iter().any(|c| !c.2.is_finite()) { continue; }
+
+            let polygon = clip(&clip(&cell, lower, true), upper, false);
+            if polygon.len() >= 3 {
+                polygons.push(polygon.iter().map(|p| (p.0, p.1)).collect());
+            }
+        }
+    }
+    polygons
+}
@@ -0,0 +1,293 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Enum used to specify how the distance between two clusters is measured when clustering.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Linkage {
+    /// Distance between the closest pair of observations, one from each cluster.
+    Single,
+    /// Distance between the furthest pair of observations, one from each cluster.
+    Complete,
+    /// Mean distance between every pair of observations, one from each cluster (default).
+    Average,
+}
+
+/// Structure used for storing chart related data and the drawing of a Dendrogram.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the tree of merges from hierarchical clustering of observations, with the height of each merge at the distance between the clusters.
+#[derive(Clone)]
+pub struct Dendrogram {
+    data_labels: Vec<String>,
+    data: Vec<Vec<f64>>,
+    linkage: Linkage,
+    merges: Vec<(usize, usize, f64)>,
+    colour_threshold: Option<f64>,
+    horizontal: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl Dendrogram {
+    /// Creates a new instance of a Dendrogram.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` specifies the label of each observation, placed at its leaf.
+    ///
+    /// ```new_data``` is the number data of each observation (each inner vector is an observation),
+    /// with indexes corresponding to the same index in new_data_labels.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data: Vec<Vec<f64>>) -> Dendrogram {
+        let linkage = Linkage::Average;
+        let merges = hierarchical_clustering(&new_data, linkage);
+
+        // the height axis is used for the y-axis of vertical dendrograms and the x-axis of horizontal ones
+        let axis_props = calc_height_axis_props(&merges);
+        let axis_bounds = axis_props.0;
+        let axis_scale = axis_props.1;
+
+        let axis_type: AxisType = AxisType::Single;
+
+        Dendrogram {
+            data_labels: new_data_labels,
+            data: new_data,
+            linkage: linkage,
+            merges: merges,
+            colour_threshold: None,
+            horizontal: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(axis_bounds, axis_bounds, axis_scale, axis_scale),
+        }
+    }
+    /// Sets how the distance between two clusters is measured (Linkage::Average by default).
+    ///
+    /// ```linkage``` is a Linkage value, use Linkage::Single or Linkage::Complete.
+    ///
+    /// The observations are clustered again and the height axis bounds recalculated.
+    pub fn set_linkage(&mut self, linkage: Linkage) {
+        self.linkage = linkage;
+        self.merges = hierarchical_clustering(&self.data, self.linkage);
+
+        let axis_props = calc_height_axis_props(&self.merges);
+        self.axis_prop.x_axis_bounds = axis_props.0;
+        self.axis_prop.y_axis_bounds = axis_props.0;
+        self.axis_prop.x_axis_scale = axis_props.1;
+        self.axis_prop.y_axis_scale = axis_props.1;
+    }
+    /// Sets a height below which each cluster is drawn in its own colour, marked by a dashed line (no threshold by default).
+    ///
+    /// ```colour_threshold``` is a positive f64 number in the units of the distances between observations.
+    pub fn set_colour_threshold(&mut self, colour_threshold: f64) {
+        self.colour_threshold = Some(colour_threshold);
+    }
+    /// Set if the leaves should be placed down the y-axis with merges to their right, in place of along the x-axis with merges above (false by default).
+    ///
+    /// ```horizontal``` is a boolean value that should be set to true to draw a horizontal dendrogram, using the bounds of the x-axis for heights.
+    pub fn set_horizontal(&mut self, horizontal: bool) {
+        self.horizontal = horizontal;
+    }
+    /// Returns the indexes of the observations in the order their leaves are drawn.
+    ///
+    /// This can be used to reorder the rows of other data (e.g. the series of a chart) to match the clustering.
+    pub fn get_leaf_order(&self) -> Vec<usize> {
+        calc_leaf_order(&self.merges, self.data.len())
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let horizontal = self.horizontal;
+        let colour_threshold = self.colour_threshold;
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let leaf_axis_title = if horizontal == true { self.axis_prop.y_axis_title.clone() } else { self.axis_prop.x_axis_title.clone() };
+        let height_axis_title = if horizontal == true { self.axis_prop.x_axis_title.clone() } else { self.axis_prop.y_axis_title.clone() };
+        let height_axis_bounds = if horizontal == true { self.axis_prop.x_axis_bounds } else { self.axis_prop.y_axis_bounds };
+        let height_axis_scale = if horizontal == true { self.axis_prop.x_axis_scale } else { self.axis_prop.y_axis_scale };
+        let height_axis_min = height_axis_bounds.0;
+        let height_axis_max = height_axis_bounds.1;
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_values = self.chart_prop.legend_values.clone();
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        let num_leaves = self.data.len();
+        let merges = self.merges.clone();
+        let leaf_order = calc_leaf_order(&merges, num_leaves);
+        let leaf_labels: Vec<String> = leaf_order.iter().map(|&i| self.data_labels.get(i).cloned().unwrap_or(String::new())).collect();
+        let leaf_axis_scale = 1.0 / (num_leaves.max(1) as f64);
+
+        // (position along the leaf axis, height) of every leaf and merged cluster,
+        // each merge is centered above the two clusters it joins
+        let mut nodes: Vec<(f64, f64)> = vec![(0.0, 0.0); num_leaves + merges.len()];
+        for (pos, &leaf) in leaf_order.iter().enumerate() {
+            nodes[leaf] = (pos as f64, 0.0);
+        }
+        for k in 0..merges.len() {
+            let (a, b, height) = merges[k];
+            nodes[num_leaves + k] = ((nodes[a].0 + nodes[b].0) / 2.0, height);
+        }
+
+        // colour of each merge, a new colour for each cluster below the threshold that joins a cluster above it
+        let mut colours: Vec<Option<usize>> = vec![None; num_leaves + merges.len()];
+        if let Some(threshold) = colour_threshold {
+            let mut next_colour = 0;
+            // merges are visited from the root so each cluster takes the colour of the cluster containing it
+            for k in (0..merges.len()).rev() {
+                let node = num_leaves + k;
+                if colours[node].is_none() && merges[k].2 < threshold {
+                    colours[node] = Some(next_colour % COLOURS.len());
+                    next_colour += 1;
+                }
+                colours[merges[k].0] = colours[node];
+                colours[merges[k].1] = colours[node];
+            }
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Dendrogram components
+
+            // screen position of a point at a position along the leaf axis and a height
+            let get_point = |pos: f64, height: f64| {
+                let height = get_percentage_in_bounds(height, height_axis_min, height_axis_max);
+                if horizontal == true {
+                    let interval = _vertical_scaling * leaf_axis_scale;
+                    (_left_bound + height * _horizontal_scaling, _upper_bound - interval / 2.0 + interval * (pos + 1.0))
+                } else {
+                    let interval = _horizontal_scaling * leaf_axis_scale;
+                    (_left_bound - interval / 2.0 + interval * (pos + 1.0), _lower_bound - height * _vertical_scaling)
+                }
+            };
+
+            // each merge is drawn as a bracket from both clusters up to its height
+            cr.set_line_width(0.003);
+            for k in 0..merges.len() {
+                let (a, b, height) = merges[k];
+                match colours[num_leaves + k] {
+                    Some(colour) => set_nth_colour(cr, colour),
+                    None => cr.set_source_rgb(0.0, 0.0, 0.0),
+                }
+                let start = get_point(nodes[a].0, nodes[a].1);
+                let start_corner = get_point(nodes[a].0, height);
+                let end_corner = get_point(nodes[b].0, height);
+                let end = get_point(nodes[b].0, nodes[b].1);
+                cr.move_to(start.0, start.1);
+                cr.line_to(start_corner.0, start_corner.1);
+                cr.line_to(end_corner.0, end_corner.1);
+                cr.line_to(end.0, end.1);
+                cr.stroke();
+            }
+
+            // dashed line across at the threshold
+            if let Some(threshold) = colour_threshold {
+                cr.save();
+                cr.set_source_rgb(0.5, 0.5, 0.5);
+                cr.set_line_width(0.0015);
+                cr.set_dash(&[0.01, 0.006], 0.0);
+                let start = get_point(-0.5, threshold);
+                let end = get_point((num_leaves as f64) - 0.5, threshold);
+                cr.move_to(start.0, start.1);
+                cr.line_to(end.0, end.1);
+                cr.stroke();
+                cr.restore();
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            if horizontal == true {
+                draw_x_axis_con(cr, scalings,
+                    height_axis_min, height_axis_max, height_axis_scale, 0.0, &height_axis_title,
+                    screen_size);
+                draw_y_axis_cat(cr, scalings,
+                    &leaf_labels, leaf_axis_scale, 0.0, &leaf_axis_title,
+                    screen_size);
+            } else {
+                draw_x_axis_cat(cr, scalings,
+                    &leaf_labels, leaf_axis_scale, 0.0, &leaf_axis_title,
+                    screen_size,
+                    false, 1);
+                draw_y_axis_con(cr, scalings,
+                    height_axis_min, height_axis_max, height_axis_scale, 0.0, &height_axis_title,
+                    screen_size);
+            }
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for Dendrogram {
+    fn draw(&self) {
+        build_window(ChartType::Dendrogram(self.clone()));
+    }
+}
+
+/*
+ * Helper functions
+ */
+
+// Order of the leaves from left to right (or top to bottom), found by following the merges down from the root.
+fn calc_leaf_order(merges: &Vec<(usize, usize, f64)>, num_leaves: usize) -> Vec<usize> {
+    if num_leaves == 0 {
+        return Vec::new();
+    }
+    let mut leaf_order: Vec<usize> = Vec::new();
+    let mut stack: Vec<usize> = vec![num_leaves + merges.len() - 1];
+    while let Some(node) = stack.pop() {
+        if node < num_leaves {
+            leaf_order.push(node);
+        } else {
+            // second cluster pushed first so the first is visited first
+            let (a, b, _) = merges[node - num_leaves];
+            stack.push(b);
+            stack.push(a);
+        }
+    }
+    leaf_order
+}
+
+// Height axis properties from zero to the highest merge.
+fn calc_height_axis_props(merges: &Vec<(usize, usize, f64)>) -> ((f64, f64), f64) {
+    let mut heights: Vec<f64> = merges.iter().map(|&(_, _, height)| height).collect();
+    heights.push(0.0);
+    calc_axis_props(&vec![heights], true, false)
+}
@@ -0,0 +1,284 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Enum used to specify the kernel used in estimating a density.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kernel {
+    /// Smooth normal distribution around each value (default).
+    Gaussian,
+    /// Parabola around each value, reaching zero at the bandwidth either side of it.
+    Epanechnikov,
+}
+
+/// Enum used to specify how the bandwidth (smoothing) of a density is chosen.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Bandwidth {
+    /// Scott's rule of thumb, calculated from the data of each series.
+    Scott,
+    /// Silverman's rule of thumb, calculated from the data of each series (default).
+    Silverman,
+    /// The same given bandwidth for every series, in the units of the data.
+    Manual(f64),
+}
+
+/// Structure used for storing chart related data and the drawing of a Density Plot.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the distribution of one or more sets of data as smooth kernel density estimate curves.
+#[derive(Clone)]
+pub struct DensityPlot {
+    data: Vec<Vec<f64>>,
+    kernel: Kernel,
+    bandwidth: Bandwidth,
+    fill: bool,
+    show_rug: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl DensityPlot {
+    /// Creates a new instance of a DensityPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data``` is the number data for each series, a density curve is estimated and drawn for each.
+    pub fn new(chart_title: String, new_data: Vec<Vec<f64>>) -> DensityPlot {
+        let kernel = Kernel::Gaussian;
+        let bandwidth = Bandwidth::Silverman;
+
+        let x_axis_props = calc_x_axis_props(&new_data, kernel, bandwidth);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+        let y_axis_bounds = (0.0, 0.0);
+        let y_axis_scale = 0.0;
+
+        let axis_type: AxisType =
+            if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else { AxisType::Single };
+
+        DensityPlot {
+            data: new_data,
+            kernel: kernel,
+            bandwidth: bandwidth,
+            fill: false,
+            show_rug: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the kernel used in estimating every density (Kernel::Gaussian by default).
+    ///
+    /// ```kernel``` is a Kernel value, use Kernel::Epanechnikov for curves that end within a bandwidth of the data.
+    ///
+    /// The x-axis bounds are recalculated so that no curve is clipped.
+    pub fn set_kernel(&mut self, kernel: Kernel) {
+        self.kernel = kernel;
+
+        let x_axis_props = calc_x_axis_props(&self.data, self.kernel, self.bandwidth);
+        self.axis_prop.x_axis_bounds = x_axis_props.0;
+        self.axis_prop.x_axis_scale = x_axis_props.1;
+    }
+    /// Sets how the bandwidth (smoothing) of every density is chosen (Bandwidth::Silverman by default).
+    ///
+    /// ```bandwidth``` is a Bandwidth value, use Bandwidth::Scott or Bandwidth::Manual(f64) with a positive number,
+    /// larger bandwidths give smoother curves.
+    ///
+    /// The x-axis bounds are recalculated so that no curve is clipped.
+    /// Manual bandwidths that are not finite and greater than zero are ignored.
+    pub fn set_bandwidth(&mut self, bandwidth: Bandwidth) {
+        if let Bandwidth::Manual(bandwidth) = bandwidth {
+            if !(bandwidth.is_finite() && bandwidth > 0.0) {
+                return;
+            }
+        }
+        self.bandwidth = bandwidth;
+
+        let x_axis_props = calc_x_axis_props(&self.data, self.kernel, self.bandwidth);
+        self.axis_prop.x_axis_bounds = x_axis_props.0;
+        self.axis_prop.x_axis_scale = x_axis_props.1;
+    }
+    /// Set if the area under each curve should be filled (false by default).
+    ///
+    /// ```fill``` is a boolean value that should be set to true to fill under curves.
+    pub fn set_fill(&mut self, fill: bool) {
+        self.fill = fill;
+    }
+    /// Set if a rug of short lines at each value of the data should be drawn along the x-axis (false by default).
+    ///
+    /// ```show_rug``` is a boolean value that should be set to true to show the rug.
+    pub fn set_show_rug(&mut self, show_rug: bool) {
+        self.show_rug = show_rug;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data = self.data.clone();
+        let fill = self.fill;
+        let show_rug = self.show_rug;
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // density of each series at evenly spaced points across the x-axis
+        let num_points = 200;
+        let mut densities: Vec<Vec<f64>> = Vec::new();
+        for j in 0..data.len() {
+            let bandwidth = get_bandwidth(&data[j], self.kernel, self.bandwidth);
+            let mut density: Vec<f64> = Vec::new();
+            for i in 0..(num_points + 1) {
+                let val = x_axis_min + (x_axis_max - x_axis_min) * (i as f64) / (num_points as f64);
+                density.push(match self.kernel {
+                    Kernel::Gaussian => calc_density(&data[j], bandwidth, val),
+                    Kernel::Epanechnikov => calc_epanechnikov_density(&data[j], bandwidth, val),
+                });
+            }
+            densities.push(density);
+        }
+
+        // generate y axis properties from densities
+        let y_axis_props = calc_axis_props(&densities, true, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Density Plot components
+            let get_x = |i: usize| _left_bound + (i as f64) / (num_points as f64) * _horizontal_scaling;
+            let get_y = |density: f64| _lower_bound - (get_percentage_in_bounds(density, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            for j in 0..densities.len() {
+                cr.move_to(get_x(0), get_y(densities[j][0]));
+                for i in 1..densities[j].len() {
+                    cr.line_to(get_x(i), get_y(densities[j][i]));
+                }
+
+                // fill down to the x-axis under the curve
+                if fill == true {
+                    cr.line_to(get_x(num_points), _lower_bound);
+                    cr.line_to(get_x(0), _lower_bound);
+                    cr.close_path();
+                    set_nth_colour_opacity(cr, j % COLOURS.len(), 0.3);
+                    cr.fill();
+
+                    cr.move_to(get_x(0), get_y(densities[j][0]));
+                    for i in 1..densities[j].len() {
+                        cr.line_to(get_x(i), get_y(densities[j][i]));
+                    }
+                }
+                set_nth_colour(cr, j % COLOURS.len());
+                cr.set_line_width(0.003);
+                cr.stroke();
+
+                // short line above the x-axis at each value
+                if show_rug == true {
+                    cr.set_line_width(0.002 * h_scale);
+                    for i in 0..data[j].len() {
+                        let x = _left_bound + (get_percentage_in_bounds(data[j][i], x_axis_min, x_axis_max) * _horizontal_scaling);
+                        cr.move_to(x, _lower_bound);
+                        cr.line_to(x, _lower_bound - 0.02 * _vertical_scaling);
+                        cr.stroke();
+                    }
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, 0.0, &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for DensityPlot {
+    fn draw(&self) {
+        build_window(ChartType::Density(self.clone()));
+    }
+}
+
+/*
+ * Helper functions
+ */
+
+// Bandwidth used for a set of data with the given kernel.
+fn get_bandwidth(data: &Vec<f64>, kernel: Kernel, bandwidth: Bandwidth) -> f64 {
+    let rule_bandwidth = match bandwidth {
+        Bandwidth::Scott => calc_scott_bandwidth(data),
+        Bandwidth::Silverman => calc_bandwidth(data),
+        Bandwidth::Manual(bandwidth) => return bandwidth,
+    };
+    // rules of thumb are for the gaussian kernel, the epanechnikov kernel is widened to give the same spread
+    match kernel {
+        Kernel::Gaussian => rule_bandwidth,
+        Kernel::Epanechnikov => rule_bandwidth * 5f64.sqrt(),
+    }
+}
+
+// x-axis properties including the tails of each density curve beyond its data.
+fn calc_x_axis_props(data: &Vec<Vec<f64>>, kernel: Kernel, bandwidth: Bandwidth) -> ((f64, f64), f64) {
+    let mut tails: Vec<Vec<f64>> = Vec::new();
+    for j in 0..data.len() {
+        let tail = match kernel {
+            Kernel::Gaussian => 3.0 * get_bandwidth(&data[j], kernel, bandwidth),
+            Kernel::Epanechnikov => get_bandwidth(&data[j], kernel, bandwidth),
+        };
+        tails.push(vec![tail; data[j].len()]);
+    }
+    calc_error_axis_props(data, &tails, &tails, false, true)
+}
@@ -0,0 +1,234 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of an ECDF Plot.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the empirical cumulative distribution of one or more sets of data, the proportion of values less than or equal to each value.
+#[derive(Clone)]
+pub struct EcdfPlot {
+    data: Vec<Vec<f64>>,
+    percentile_markers: Vec<f64>,
+    complementary: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl EcdfPlot {
+    /// Creates a new instance of an EcdfPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data``` is the number data for each series, a step line of the cumulative distribution is drawn for each non empty series.
+    pub fn new(chart_title: String, new_data: Vec<Vec<f64>>) -> EcdfPlot {
+        let non_empty_data: Vec<Vec<f64>> = new_data.iter().filter(|series| series.len() > 0).cloned().collect();
+        let x_axis_props = calc_axis_props(&non_empty_data, false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+        let y_axis_bounds = (0.0, 1.0);
+        let y_axis_scale = 0.1;
+
+        let axis_type: AxisType =
+            if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else { AxisType::Single };
+
+        EcdfPlot {
+            data: new_data,
+            percentile_markers: Vec::new(),
+            complementary: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the percentiles marked on each line, with a guide line across the chart labelled with each percentile (none by default).
+    ///
+    /// ```percentiles``` are f64 numbers between 0 and 1, e.g. vec![0.5, 0.9, 0.99] marks p50, p90 and p99,
+    /// values outside of this range are ignored.
+    pub fn set_percentile_markers(&mut self, percentiles: Vec<f64>) {
+        self.percentile_markers = percentiles.into_iter().filter(|&p| p >= 0.0 && p <= 1.0).collect();
+    }
+    /// Set if the complementary distribution (1 - CDF), the proportion of values greater than each value, should be drawn on a logarithmic y-axis (false by default).
+    ///
+    /// ```complementary``` is a boolean value that should be set to true to draw the complementary distribution, showing detail in the tail of the data.
+    pub fn set_complementary(&mut self, complementary: bool) {
+        self.complementary = complementary;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let complementary = self.complementary;
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        let mut sorted_data = self.data.clone();
+        for j in 0..sorted_data.len() {
+            sorted_data[j].sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        }
+
+        // value at each percentile marked for each series, empty series have no values to mark
+        let percentile_markers = self.percentile_markers.clone();
+        let mut marker_values: Vec<Vec<f64>> = Vec::new();
+        for j in 0..sorted_data.len() {
+            if sorted_data[j].len() == 0 {
+                marker_values.push(Vec::new());
+            } else {
+                marker_values.push(percentile_markers.iter().map(|&p| percentile(&sorted_data[j], p)).collect());
+            }
+        }
+
+        // y-axis is either proportions from 0 to 1, or the powers of 10 down to the smallest proportion above 0 in the complementary distribution
+        let y_axis_min;
+        let y_axis_max;
+        let y_axis_scale;
+        if complementary == true {
+            let max_len = sorted_data.iter().fold(1, |max_len, series| max_len.max(series.len()));
+            y_axis_min = (1.0 / (max_len as f64)).log10().floor().min(-1.0);
+            y_axis_max = 0.0;
+            y_axis_scale = 1.0 / (y_axis_max - y_axis_min);
+        } else {
+            y_axis_min = self.axis_prop.y_axis_bounds.0;
+            y_axis_max = self.axis_prop.y_axis_bounds.1;
+            y_axis_scale = self.axis_prop.y_axis_scale;
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing ECDF Plot components
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            // proportions of 0 are at the bottom of the logarithmic axis
+            let get_y = |proportion: f64| {
+                let y_val = if complementary == true { proportion.max((10.0 as f64).powf(y_axis_min)).log10() } else { proportion };
+                _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling)
+            };
+            let get_proportion = |cumulative: f64| if complementary == true { 1.0 - cumulative } else { cumulative };
+
+            // guide line across the chart at each percentile
+            cr.save();
+            cr.set_source_rgb(0.5, 0.5, 0.5);
+            cr.set_line_width(0.001);
+            cr.set_dash(&[0.006, 0.004], 0.0);
+            cr.set_font_size(0.018);
+            let mut font_matrix = cr.get_font_matrix();
+            font_matrix.scale(h_scale, v_scale);
+            cr.set_font_matrix(font_matrix);
+            for k in 0..percentile_markers.len() {
+                let y = get_y(get_proportion(percentile_markers[k]));
+                cr.move_to(_left_bound, y);
+                cr.line_to(_right_bound, y);
+                cr.stroke();
+
+                let label = format!("p{}", (percentile_markers[k] * 1000.0).round() / 10.0);
+                let text_width = cr.text_extents(label.as_str()).width;
+                cr.move_to(_right_bound - text_width, y - 0.005);
+                cr.show_text(label.as_str());
+            }
+            cr.restore();
+
+            use std::f64::consts::PI;
+            for j in 0..sorted_data.len() {
+                // empty series are not drawn
+                if sorted_data[j].len() == 0 { continue; }
+                let n = sorted_data[j].len() as f64;
+
+                // step up (or down) by 1/n at each value, from the left to the right of the x-axis
+                let mut prev = (get_x(x_axis_min), get_y(get_proportion(0.0)));
+                cr.move_to(prev.0, prev.1);
+                for i in 0..sorted_data[j].len() {
+                    let cur = (get_x(sorted_data[j][i]), get_y(get_proportion(((i + 1) as f64) / n)));
+                    for point in get_step_points(prev, cur, StepMode::Post) {
+                        cr.line_to(point.0, point.1);
+                    }
+                    cr.line_to(cur.0, cur.1);
+                    prev = cur;
+                }
+                cr.line_to(get_x(x_axis_max), prev.1);
+                set_nth_colour(cr, j % COLOURS.len());
+                cr.set_line_width(0.003);
+                cr.stroke();
+
+                // mark on the line at each percentile
+                for k in 0..percentile_markers.len() {
+                    cr.save();
+                    cr.translate(get_x(marker_values[j][k]), get_y(get_proportion(percentile_markers[k])));
+                    cr.scale(h_scale, v_scale);
+                    cr.arc(0.0, 0.0, 0.006, 0.0, 2.0 * PI);
+                    cr.fill();
+                    cr.restore();
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, 0.0, &x_axis_title,
+                screen_size);
+            if complementary == true {
+                draw_y_axis_log(cr, scalings,
+                    y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                    screen_size);
+            } else {
+                draw_y_axis_con(cr, scalings,
+                    y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                    screen_size);
+            }
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for EcdfPlot {
+    fn draw(&self) {
+        build_window(ChartType::Ecdf(self.clone()));
+    }
+}
@@ -0,0 +1,190 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Function Plot.
+///
+/// Shows the curves of functions over a range of x, or parametric curves, without precomputing data.
+#[derive(Clone)]
+pub struct FunctionPlot {
+    x_range: (f64, f64),
+    curves: Vec<Vec<Vec<(f64, f64)>>>,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl FunctionPlot {
+    /// Creates a new instance of a FunctionPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```x_min``` and ```x_max``` are the range of x over which functions are drawn.
+    ///
+    /// Curves are added using add_function() and add_parametric(), each is drawn in the next colour.
+    pub fn new(chart_title: String, x_min: f64, x_max: f64) -> FunctionPlot {
+        let x_axis_props = calc_axis_props(&vec![vec![x_min, x_max]], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![vec![0.0, 1.0]], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else { AxisType::Single };
+
+        FunctionPlot {
+            x_range: (x_min, x_max),
+            curves: Vec::new(),
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Adds the curve of a function y = function(x) over the range of x.
+    ///
+    /// ```function``` is a closure taking x and returning y, it is sampled with more points where the curve bends.
+    /// The curve is broken where the function jumps or returns a non-finite number (e.g. 1.0 / x at 0).
+    ///
+    /// The axis bounds are recalculated to fit every curve, functions that grow very large (e.g. tan) may need
+    /// the y-axis bounds set using axis_prop.set_y_axis_bounds() after adding every curve, the curves are cut off at the axis.
+    pub fn add_function<F: Fn(f64) -> f64>(&mut self, function: F) {
+        let curve = sample_curve(&|x: f64| (x, function(x)), self.x_range.0, self.x_range.1);
+        self.add_curve(curve);
+    }
+    /// Adds a parametric curve (x, y) = function(t) for t from t_min to t_max.
+    ///
+    /// ```function``` is a closure taking t and returning the point (x, y), it is sampled with more points where the curve bends.
+    ///
+    /// ```t_min``` and ```t_max``` are the range of t, e.g. 0 to 2π for a circle.
+    ///
+    /// The axis bounds are recalculated to fit every curve.
+    pub fn add_parametric<F: Fn(f64) -> (f64, f64)>(&mut self, function: F, t_min: f64, t_max: f64) {
+        let curve = sample_curve(&function, t_min, t_max);
+        self.add_curve(curve);
+    }
+    fn add_curve(&mut self, curve: Vec<Vec<(f64, f64)>>) {
+        self.curves.push(curve);
+
+        // x-axis always includes the range of x
+        let mut x_values: Vec<f64> = vec![self.x_range.0, self.x_range.1];
+        let mut y_values: Vec<f64> = Vec::new();
+        for line in self.curves.iter().flat_map(|curve| curve.iter()) {
+            x_values.extend(line.iter().map(|point| point.0));
+            y_values.extend(line.iter().map(|point| point.1));
+        }
+        if y_values.len() == 0 {
+            return;
+        }
+
+        let x_axis_props = calc_axis_props(&vec![x_values], false, true);
+        self.axis_prop.x_axis_bounds = x_axis_props.0;
+        self.axis_prop.x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![y_values], false, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let curves = self.curves.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Function Plot components
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            // curves are cut off at the axis
+            cr.save();
+            cr.rectangle(_left_bound, _lower_bound - _vertical_scaling, _horizontal_scaling, _vertical_scaling);
+            cr.clip();
+
+            cr.set_line_width(0.003);
+            for j in 0..curves.len() {
+                set_nth_colour(cr, j % COLOURS.len());
+                for line in curves[j].iter() {
+                    cr.move_to(get_x(line[0].0), get_y(line[0].1));
+                    for i in 1..line.len() {
+                        cr.line_to(get_x(line[i].0), get_y(line[i].1));
+                    }
+                    cr.stroke();
+                }
+            }
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for FunctionPlot {
+    fn draw(&self) {
+        build_window(ChartType::Function(self.clone()));
+    }
+}
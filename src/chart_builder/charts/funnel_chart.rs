@@ -0,0 +1,170 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Funnel Chart.
+///
+/// Shows how a value decreases through ordered stages of a process, e.g. conversions in a sales pipeline.
+#[derive(Clone)]
+pub struct FunnelChart {
+    data_labels: Vec<String>,
+    data: Vec<f64>,
+    pub chart_prop: ChartProp,
+}
+
+impl FunnelChart {
+    /// Creates a new instance of a FunnelChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` contains the name of each stage, in order from the first (top) stage to the last.
+    ///
+    /// ```new_data``` is the number data for the value at each stage, with indexes corresponding to the same index in new_data_labels.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data: Vec<f64>) -> FunnelChart {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        FunnelChart {
+            data_labels: new_data_labels,
+            data: new_data,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let data = self.data.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // Calculate proportions of each stage relative to the largest, first and previous stages,
+        // there is no proportion of a stage with a value of zero
+        let max: f64 = data.iter().fold(0.0, |cur_max: f64, &x| cur_max.max(x));
+        let get_proportion = |value: f64, of_value: f64| if of_value != 0.0 { Some(value / of_value) } else { None };
+        let first = data.get(0).cloned().unwrap_or(0.0);
+        let mut widths: Vec<f64> = Vec::new();
+        let mut of_first: Vec<Option<f64>> = Vec::new();
+        let mut of_previous: Vec<Option<f64>> = Vec::new();
+        for i in 0..data.len() {
+            widths.push(if max > 0.0 { data[i].max(0.0) / max } else { 0.0 });
+            of_first.push(get_proportion(data[i], first));
+            of_previous.push(if i == 0 { Some(1.0) } else { get_proportion(data[i], data[i - 1]) });
+        }
+
+        let dps: usize = if max >= 100.0 { 0 } else { 2 };
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Funnel chart components
+
+            // funnel is centred with space for stage names on the left and percentages on the right
+            let x = _left_bound + 0.5 * _horizontal_scaling;
+            let max_width = 0.5 * _horizontal_scaling;
+            let stage_height = _vertical_scaling / (data.len() as f64);
+            let stage_gap = stage_height * 0.06;
+
+            cr.set_font_size(0.02);
+            let mut font_matrix = cr.get_font_matrix();
+            font_matrix.scale(h_scale, v_scale);
+            cr.set_font_matrix(font_matrix);
+
+            for i in 0..data.len() {
+                let top = _upper_bound + stage_height * (i as f64);
+                let bottom = top + stage_height - stage_gap;
+                let top_width = widths[i] * max_width;
+                // each stage narrows to the width of the next, the last stage keeps its width
+                let bottom_width = if i + 1 < data.len() { widths[i + 1] * max_width } else { top_width };
+
+                // Draw trapezoid of stage
+                set_nth_colour(cr, i % COLOURS.len());
+                cr.move_to(x - top_width / 2.0, top);
+                cr.line_to(x + top_width / 2.0, top);
+                cr.line_to(x + bottom_width / 2.0, bottom);
+                cr.line_to(x - bottom_width / 2.0, bottom);
+                cr.close_path();
+                cr.fill();
+
+                let centre_y = (top + bottom) / 2.0;
+
+                // Draw value in centre of stage
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                let value_string = format!("{:.*}", dps, data[i]);
+                let value_str = value_string.as_str();
+                let text_width = cr.text_extents(value_str).width;
+                let text_height = cr.text_extents(value_str).height;
+                cr.move_to(x - text_width / 2.0, centre_y + text_height / 2.0);
+                cr.show_text(value_str);
+
+                // Draw stage name to the left
+                let label_str = data_labels.get(i).map(|label| label.as_str()).unwrap_or("");
+                let text_width = cr.text_extents(label_str).width;
+                cr.move_to(x - max_width / 2.0 - 0.02 * h_scale - text_width, centre_y + text_height / 2.0);
+                cr.show_text(label_str);
+
+                // Draw percentage of first and previous stages to the right
+                let get_percentage_string = |proportion: Option<f64>| match proportion {
+                    Some(proportion) => format!("{:.*}%", 1, proportion * 100.0),
+                    None => String::from("–"),
+                };
+                let first_string = format!("{} of first", get_percentage_string(of_first[i]));
+                let previous_string = format!("{} of previous", get_percentage_string(of_previous[i]));
+                cr.move_to(x + max_width / 2.0 + 0.02 * h_scale, centre_y - text_height * 0.25);
+                cr.show_text(first_string.as_str());
+                if i != 0 {
+                    cr.move_to(x + max_width / 2.0 + 0.02 * h_scale, centre_y + text_height * 1.25);
+                    cr.show_text(previous_string.as_str());
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for FunnelChart {
+    fn draw(&self) {
+        build_window(ChartType::Funnel(self.clone()));
+    }
+}
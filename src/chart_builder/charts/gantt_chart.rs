@@ -0,0 +1,293 @@
+//!
+
+
+use chart_builder::charts::*;
+use data_parser;
+
+/// Structure used for storing chart related data and the drawing of a Gantt Chart.
+///
+/// Shows a schedule of tasks as horizontal bars from their start to their end.
+#[derive(Clone)]
+pub struct GanttChart {
+    task_names: Vec<String>,
+    data_start: Vec<f64>,
+    data_end: Vec<f64>,
+    groups: Vec<String>,
+    progress: Vec<f64>,
+    dependencies: Vec<(usize, usize)>,
+    today: Option<f64>,
+    date_axis: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl GanttChart {
+    /// Creates a new instance of a GanttChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_task_names``` contains the name of each task, placed on the y-axis from top to bottom.
+    ///
+    /// ```new_data_start``` is the number data placed on the x-axis specifying where each task starts,
+    /// with indexes corresponding to the same index in new_task_names.
+    ///
+    /// ```new_data_end``` is the number data placed on the x-axis specifying where each task ends,
+    /// with indexes corresponding to the same index in new_task_names.
+    pub fn new(chart_title: String, new_task_names: Vec<String>, new_data_start: Vec<f64>, new_data_end: Vec<f64>) -> GanttChart {
+        let x_axis_props = calc_axis_props(&vec![new_data_start.clone(), new_data_end.clone()], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+        let y_axis_bounds = (0.0, 0.0);
+        let y_axis_scale = 1.0 / (new_task_names.len() as f64);
+
+        let axis_type: AxisType = AxisType::Single;
+
+        GanttChart {
+            task_names: new_task_names,
+            data_start: new_data_start,
+            data_end: new_data_end,
+            groups: Vec::new(),
+            progress: Vec::new(),
+            dependencies: Vec::new(),
+            today: None,
+            date_axis: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Creates a new instance of a GanttChart where tasks start and end on dates, labelling the x-axis with dates.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_task_names``` contains the name of each task, placed on the y-axis from top to bottom.
+    ///
+    /// ```new_start_dates``` and ```new_end_dates``` are the dates (in the format ```YYYY-MM-DD```) each task starts and ends,
+    /// with indexes corresponding to the same index in new_task_names.
+    pub fn from_dates(chart_title: String, new_task_names: Vec<String>, new_start_dates: Vec<String>, new_end_dates: Vec<String>) -> GanttChart {
+        let mut gantt_chart = GanttChart::new(chart_title, new_task_names,
+            data_parser::vec_date_to_num(&new_start_dates),
+            data_parser::vec_date_to_num(&new_end_dates));
+        gantt_chart.date_axis = true;
+        // dates are wider than numbers so fewer fit on the axis
+        gantt_chart.axis_prop.x_axis_scale = gantt_chart.axis_prop.x_axis_scale.max(0.2);
+        gantt_chart
+    }
+    /// Sets the group each task belongs to, tasks in the same group are drawn in the same colour.
+    ///
+    /// ```new_groups``` contains the name of the group of each task, with indexes corresponding to the same index in the task names.
+    ///
+    /// The names of the groups are used as the legend values and the legend is shown.
+    pub fn set_groups(&mut self, new_groups: Vec<String>) {
+        self.groups = new_groups;
+        let legend_values = get_distinct_groups(&self.groups);
+        self.chart_prop.set_legend_values(legend_values);
+        self.chart_prop.set_show_legend(true);
+    }
+    /// Sets how much of each task has been completed, shown by shading part of its bar.
+    ///
+    /// ```new_progress``` contains the completed proportion (between 0.0 and 1.0) of each task,
+    /// with indexes corresponding to the same index in the task names.
+    pub fn set_progress(&mut self, new_progress: Vec<f64>) {
+        self.progress = new_progress;
+    }
+    /// Sets dependencies between tasks, each drawn as an arrow from the end of one task to the start of another.
+    ///
+    /// ```new_dependencies``` contains pairs of task indexes, where the second task depends on the first,
+    /// pairs with an index that is not a task are ignored.
+    pub fn set_dependencies(&mut self, new_dependencies: Vec<(usize, usize)>) {
+        let num_tasks = self.task_names.len().min(self.data_start.len()).min(self.data_end.len());
+        self.dependencies = new_dependencies.into_iter().filter(|&(from, to)| from < num_tasks && to < num_tasks).collect();
+    }
+    /// Sets the position of a vertical line marking the current time (not shown by default).
+    ///
+    /// ```today``` is the position on the x-axis of the line,
+    /// for charts created from dates use ```data_parser::date_to_num()``` to convert a date to a position.
+    ///
+    /// The x-axis is extended to include the line if it is outside of the current bounds.
+    pub fn set_today(&mut self, today: f64) {
+        self.today = Some(today);
+        let x_axis_bounds = self.axis_prop.x_axis_bounds;
+        if today < x_axis_bounds.0 || today > x_axis_bounds.1 {
+            let x_axis_props = calc_axis_props(&vec![self.data_start.clone(), self.data_end.clone(), vec![today]], false, true);
+            self.axis_prop.x_axis_bounds = x_axis_props.0;
+            self.axis_prop.x_axis_scale = if self.date_axis == true { x_axis_props.1.max(0.2) } else { x_axis_props.1 };
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let task_names = self.task_names.clone();
+        let data_start = self.data_start.clone();
+        let data_end = self.data_end.clone();
+        let progress = self.progress.clone();
+        let dependencies = self.dependencies.clone();
+        let today = self.today;
+        let date_axis = self.date_axis;
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+
+        // Actual size of screen generate if legend section is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // index of the colour used for each task, from the order groups first appear
+        let distinct_groups = get_distinct_groups(&self.groups);
+        let mut colour_index: Vec<usize> = Vec::new();
+        for i in 0..task_names.len() {
+            if i < self.groups.len() {
+                colour_index.push(distinct_groups.iter().position(|g| *g == self.groups[i]).unwrap() % COLOURS.len());
+            } else {
+                colour_index.push(0);
+            }
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Gantt chart components
+            let y_delimiter_interval: f64 = _vertical_scaling * y_axis_scale;
+            let bar_height = y_delimiter_interval * 0.6;
+
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            let get_y = |i: usize| _upper_bound - (y_delimiter_interval / 2.0) + y_delimiter_interval * ((i + 1) as f64);
+
+            cr.set_line_width(0.002);
+            for i in 0..task_names.len() {
+                let x = get_x(data_start[i]);
+                let width = get_x(data_end[i]) - x;
+                let y = get_y(i) - bar_height / 2.0;
+
+                // completed part of task in full colour, remaining part faded
+                if i < progress.len() {
+                    set_nth_colour_opacity(cr, colour_index[i], 0.35);
+                    cr.rectangle(x, y, width, bar_height);
+                    cr.fill();
+                    set_nth_colour(cr, colour_index[i]);
+                    cr.rectangle(x, y, width * progress[i].max(0.0).min(1.0), bar_height);
+                    cr.fill();
+                } else {
+                    set_nth_colour(cr, colour_index[i]);
+                    cr.rectangle(x, y, width, bar_height);
+                    cr.fill();
+                }
+
+                // bar outline
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.rectangle(x, y, width, bar_height);
+                cr.stroke();
+            }
+
+            // Draw dependency arrows from the end of a task to the start of the task depending on it
+            use std::f64::consts::PI;
+            let arrow_gap = 0.015 * h_scale;
+            let arrow_size = 0.012;
+            cr.set_source_rgba(0.0, 0.0, 0.0, 0.8);
+            for &(from, to) in dependencies.iter() {
+                let start_x = get_x(data_end[from]);
+                let start_y = get_y(from);
+                let end_x = get_x(data_start[to]);
+                let end_y = get_y(to);
+                let elbow_x = start_x + arrow_gap;
+
+                cr.move_to(start_x, start_y);
+                cr.line_to(elbow_x, start_y);
+                cr.line_to(elbow_x, end_y);
+                cr.line_to(end_x, end_y);
+                cr.stroke();
+
+                let angle = if end_x >= elbow_x { 0.0 } else { PI };
+                draw_arrow_head(cr, end_x, end_y, angle, arrow_size, h_scale, v_scale);
+            }
+
+            // Draw today marker line
+            if let Some(today_val) = today {
+                let x = get_x(today_val);
+                cr.save();
+                cr.set_source_rgb(COLOURS[0].0, COLOURS[0].1, COLOURS[0].2);
+                cr.set_line_width(0.003 * h_scale);
+                cr.set_dash(&[0.01, 0.006], 0.0);
+                cr.move_to(x, _upper_bound);
+                cr.line_to(x, _lower_bound);
+                cr.stroke();
+                cr.restore();
+
+                cr.set_source_rgb(COLOURS[0].0, COLOURS[0].1, COLOURS[0].2);
+                cr.set_font_size(0.016);
+                let mut font_matrix = cr.get_font_matrix();
+                font_matrix.scale(h_scale, v_scale);
+                cr.set_font_matrix(font_matrix);
+                let text_width = cr.text_extents("Today").width;
+                cr.move_to(x - text_width / 2.0, _upper_bound - 0.008);
+                cr.show_text("Today");
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            if date_axis == true {
+                draw_x_axis_date(cr, scalings,
+                    x_axis_min, x_axis_max, x_axis_scale, 0.0, &x_axis_title,
+                    screen_size);
+            } else {
+                draw_x_axis_con(cr, scalings,
+                    x_axis_min, x_axis_max, x_axis_scale, 0.0, &x_axis_title,
+                    screen_size);
+            }
+            draw_y_axis_cat(cr, scalings,
+                &task_names, y_axis_scale, 0.0, &y_axis_title,
+                screen_size);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for GanttChart {
+    fn draw(&self) {
+        build_window(ChartType::Gantt(self.clone()));
+    }
+}
@@ -0,0 +1,243 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Gauge Chart.
+///
+/// Shows a single value within a range on a dial, such as progress towards a target.
+#[derive(Clone)]
+pub struct GaugeChart {
+    value: f64,
+    min: f64,
+    max: f64,
+    arc_angle: f64,
+    thresholds: Vec<f64>,
+    show_needle: bool,
+    pub chart_prop: ChartProp,
+}
+
+impl GaugeChart {
+    /// Creates a new instance of a GaugeChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_value``` is the number shown by the gauge, values outside of the range are drawn at the nearest end of the dial.
+    ///
+    /// ```new_min``` and ```new_max``` are the numbers at the start and end of the dial.
+    pub fn new(chart_title: String, new_value: f64, new_min: f64, new_max: f64) -> GaugeChart {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        GaugeChart {
+            value: new_value,
+            min: new_min,
+            max: new_max,
+            arc_angle: 180.0,
+            thresholds: Vec::new(),
+            show_needle: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    /// Sets the angle of the dial in degrees (180.0, a semicircle, by default).
+    ///
+    /// ```arc_angle``` is a f64 number between 0 and 360, e.g. 270.0 for a dial open at the bottom.
+    pub fn set_arc_angle(&mut self, arc_angle: f64) {
+        self.arc_angle = arc_angle.max(1.0).min(360.0);
+    }
+    /// Sets values splitting the range of the dial into bands, each drawn in the next colour (no bands by default).
+    ///
+    /// ```thresholds``` are numbers between the min and max in increasing order, e.g. vec![60.0, 85.0] gives three bands.
+    /// Legend values can be used to label each band.
+    pub fn set_thresholds(&mut self, thresholds: Vec<f64>) {
+        self.thresholds = thresholds;
+    }
+    /// Set if a needle should point to the value, in place of filling the dial up to it (false by default).
+    ///
+    /// ```show_needle``` is a boolean value that should be set to true to show a needle.
+    pub fn set_show_needle(&mut self, show_needle: bool) {
+        self.show_needle = show_needle;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let value = self.value;
+        let min = self.min;
+        let max = self.max;
+        let show_needle = self.show_needle;
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let show_legend = self.chart_prop.show_legend;
+        let mut screen_size = self.chart_prop.screen_size;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // dial is symmetric about the top of the circle, going clockwise from min to max
+        use std::f64::consts::PI;
+        let sweep = self.arc_angle / 180.0 * PI;
+        let start_rad = - PI / 2.0 - sweep / 2.0;
+        let end_rad = - PI / 2.0 + sweep / 2.0;
+        let get_rad = |val: f64| start_rad + get_percentage_in_bounds(val.max(min).min(max), min, max) * sweep;
+
+        // (start, end) of each band from min to max
+        let mut bands: Vec<(f64, f64)> = Vec::new();
+        let mut band_start = min;
+        for i in 0..self.thresholds.len() {
+            let threshold = self.thresholds[i].max(band_start).min(max);
+            bands.push((band_start, threshold));
+            band_start = threshold;
+        }
+        if self.thresholds.len() > 0 {
+            bands.push((band_start, max));
+        }
+        // band the value is in, used to colour the filled dial
+        let value_band = bands.iter().position(|&(_, end)| value <= end).unwrap_or(bands.len().max(1) - 1);
+
+        let band_rads: Vec<(f64, f64)> = bands.iter().map(|&(start, end)| (get_rad(start), get_rad(end))).collect();
+        let value_rad = get_rad(value);
+
+        let dps: usize;
+        if max.abs().max(min.abs()) <= 0.1 { dps = 4; }
+        else if max.abs().max(min.abs()) >= 100.0  { dps = 0; }
+        else { dps = 2; }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Gauge chart Components
+
+            let x = _left_bound + 0.5 * _horizontal_scaling;
+            let y = _lower_bound - 0.5 * _vertical_scaling;
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let max_radius = 0.45 * radius_scaling;
+            let min_radius = 0.30 * radius_scaling;
+            let band_width = 0.025 * radius_scaling;
+
+            // distance of the lowest point of the dial below its center, used to center the dial vertically
+            let lowest = (max_radius * end_rad.sin()).max(0.0);
+
+            cr.save();
+            // Moving drawing origin to the center of the dial
+            cr.translate(x, y);
+            // Scaling the current transformation matrix by different amounts in the X and Y directions.
+            // This is done to assure a circlular object in a rectangular screen.
+            cr.scale(h_scale, v_scale);
+            cr.translate(0.0, (max_radius - lowest) / 2.0);
+
+            // Draw a section of the dial between two radii
+            let draw_section = |outer_radius: f64, inner_radius: f64, start: f64, end: f64| {
+                cr.arc(0.0, 0.0, outer_radius, start, end);
+                cr.arc_negative(0.0, 0.0, inner_radius, end, start);
+                cr.close_path();
+                cr.fill();
+            };
+
+            if show_needle == true {
+                // dial coloured by bands, with a needle to the value
+                if band_rads.len() > 0 {
+                    for i in 0..band_rads.len() {
+                        set_nth_colour(cr, i % COLOURS.len());
+                        draw_section(max_radius, min_radius, band_rads[i].0, band_rads[i].1);
+                    }
+                } else {
+                    cr.set_source_rgb(0.85, 0.85, 0.85);
+                    draw_section(max_radius, min_radius, start_rad, end_rad);
+                }
+
+                cr.set_source_rgb(0.2, 0.2, 0.2);
+                let needle_width = 0.02 * radius_scaling;
+                cr.move_to(max_radius * 0.95 * value_rad.cos(), max_radius * 0.95 * value_rad.sin());
+                cr.line_to(needle_width * (value_rad + PI / 2.0).cos(), needle_width * (value_rad + PI / 2.0).sin());
+                cr.line_to(needle_width * (value_rad - PI / 2.0).cos(), needle_width * (value_rad - PI / 2.0).sin());
+                cr.close_path();
+                cr.fill();
+                cr.arc(0.0, 0.0, needle_width * 1.5, 0.0, 2.0 * PI);
+                cr.fill();
+            } else {
+                // grey dial filled up to the value, with bands in a thin ring outside
+                cr.set_source_rgb(0.85, 0.85, 0.85);
+                draw_section(max_radius, min_radius, start_rad, end_rad);
+
+                set_nth_colour(cr, value_band % COLOURS.len());
+                draw_section(max_radius, min_radius, start_rad, value_rad);
+
+                for i in 0..band_rads.len() {
+                    set_nth_colour(cr, i % COLOURS.len());
+                    draw_section(max_radius + band_width * 1.5, max_radius + band_width * 0.5, band_rads[i].0, band_rads[i].1);
+                }
+            }
+
+            // min and max below each end of the dial
+            cr.set_source_rgb(0.0, 0.0, 0.0);
+            cr.set_font_size(0.024);
+            let end_labels = [(format!("{:.*}", dps, min), start_rad), (format!("{:.*}", dps, max), end_rad)];
+            for &(ref label, rad) in end_labels.iter() {
+                let text_width = cr.text_extents(label.as_str()).width;
+                let text_height = cr.text_extents(label.as_str()).height;
+                let label_radius = (max_radius + min_radius) / 2.0;
+                cr.move_to(label_radius * rad.cos() - text_width / 2.0, label_radius * rad.sin() + text_height * 2.0);
+                cr.show_text(label.as_str());
+            }
+
+            // value in the center of the dial, sitting on the center line of a semicircle or below the needle
+            cr.set_font_size(0.08);
+            let value_str = format!("{:.*}", dps, value);
+            let text_width = cr.text_extents(value_str.as_str()).width;
+            let text_height = cr.text_extents(value_str.as_str()).height;
+            let text_y = if show_needle == true { text_height * 1.5 } else if lowest > 0.0 { text_height / 2.0 } else { 0.0 };
+            cr.move_to(- text_width / 2.0, text_y);
+            cr.show_text(value_str.as_str());
+
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for GaugeChart {
+    fn draw(&self) {
+        build_window(ChartType::Gauge(self.clone()));
+    }
+}
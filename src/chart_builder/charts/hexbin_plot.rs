@@ -0,0 +1,250 @@
+//!
+
+
+use chart_builder::charts::*;
+use std::collections::HashMap;
+
+/// Structure used for storing chart related data and the drawing of a Hexbin Plot.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the density of a large number of (x, y) points by counting the points within hexagonal bins, coloured on a colour scale.
+#[derive(Clone)]
+pub struct HexbinPlot {
+    data_x: Vec<f64>,
+    data_y: Vec<f64>,
+    data_values: Vec<f64>,
+    grid_size: usize,
+    hex_width: Option<f64>,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl HexbinPlot {
+    /// Creates a new instance of a HexbinPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_x``` is the number data placed on the x-axis of the chart, specifying horizontal positions of points.
+    ///
+    /// ```new_data_y``` is the number data placed on the y-axis of the chart, specifying vertical positions of points, with indexes corresponding to the same index in new_data_x.
+    ///
+    /// A colour bar is shown in the legend section of the window by default, this can be hidden using chart_prop.set_show_legend(false).
+    pub fn new(chart_title: String, new_data_x: Vec<f64>, new_data_y: Vec<f64>) -> HexbinPlot {
+        let x_axis_props = calc_axis_props(&vec![new_data_x.clone()], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![new_data_y.clone()], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        let mut chart_prop = ChartProp::new(chart_title, &axis_type);
+        chart_prop.set_show_legend(true);
+
+        HexbinPlot {
+            data_x: new_data_x,
+            data_y: new_data_y,
+            data_values: Vec::new(),
+            grid_size: 20,
+            hex_width: None,
+            chart_prop: chart_prop,
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the number of hexagons across the width of the x-axis (20 by default).
+    ///
+    /// ```grid_size``` is the number of hexagons, at least 1.
+    pub fn set_grid_size(&mut self, grid_size: usize) {
+        self.grid_size = grid_size.max(1);
+        self.hex_width = None;
+    }
+    /// Sets the width of each hexagon in the units of the x-axis data, in place of the grid size.
+    ///
+    /// ```hex_width``` is a positive f64 number, the height of hexagons follows so they remain regular on screen.
+    /// Widths that are not finite and greater than zero are ignored.
+    pub fn set_hex_width(&mut self, hex_width: f64) {
+        if !(hex_width.is_finite() && hex_width > 0.0) {
+            return;
+        }
+        self.hex_width = Some(hex_width);
+    }
+    /// Sets a third value for each point, hexagons are coloured by the mean of these values in place of the number of points.
+    ///
+    /// ```new_data_values``` is the number data for each point, with indexes corresponding to the same index in the x and y data.
+    pub fn set_aggregate_values(&mut self, new_data_values: Vec<f64>) {
+        self.data_values = new_data_values;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        // Actual size of screen generate if legend section (colour bar) is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // Hexagons are binned in pixels from the bottom left of the plot area so they are regular on screen
+        let plot_width = _horizontal_scaling * screen_size.0;
+        let plot_height = _vertical_scaling * screen_size.1;
+        let hex_width = match self.hex_width {
+            Some(width) => width / (x_axis_max - x_axis_min) * plot_width,
+            None => plot_width / (self.grid_size as f64),
+        };
+        // pointy topped hexagons, radius from center to each corner
+        let hex_radius = hex_width / 3f64.sqrt();
+
+        // count points (and total their values) in each hexagon by axial coordinates, points outside of the axis are ignored
+        let aggregate = self.data_values.len() > 0;
+        let mut bins: HashMap<(i64, i64), (f64, f64)> = HashMap::new();
+        for i in 0..self.data_x.len().min(self.data_y.len()) {
+            let x_val = self.data_x[i];
+            let y_val = self.data_y[i];
+            if !(x_val >= x_axis_min && x_val <= x_axis_max && y_val >= y_axis_min && y_val <= y_axis_max) {
+                continue;
+            }
+            let x = get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * plot_width;
+            let y = get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * plot_height;
+
+            let bin = calc_hex_coords(x, y, hex_radius);
+            let entry = bins.entry(bin).or_insert((0.0, 0.0));
+            entry.0 += 1.0;
+            entry.1 += self.data_values.get(i).cloned().unwrap_or(0.0);
+        }
+
+        // center of each hexagon (in pixels) with the value shown
+        let mut hexagons: Vec<(f64, f64, f64)> = Vec::new();
+        for (&(q, r), &(count, total)) in bins.iter() {
+            let x = hex_radius * 3f64.sqrt() * ((q as f64) + (r as f64) / 2.0);
+            let y = hex_radius * 1.5 * (r as f64);
+            hexagons.push((x, y, if aggregate == true { total / count } else { count }));
+        }
+        let colour_min = hexagons.iter().fold(0./0., |cur_min: f64, h| cur_min.min(h.2));
+        let colour_max = hexagons.iter().fold(0./0., |cur_max: f64, h| cur_max.max(h.2));
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Hexbin Plot components
+
+            // hexagons on the edges are cut off at the axis
+            cr.save();
+            cr.rectangle(_left_bound, _lower_bound - _vertical_scaling, _horizontal_scaling, _vertical_scaling);
+            cr.clip();
+
+            use std::f64::consts::PI;
+            for &(x, y, value) in hexagons.iter() {
+                set_scale_colour(cr, get_percentage_in_bounds(value, colour_min, colour_max));
+                for k in 0..6 {
+                    let angle = PI / 6.0 + (k as f64) * PI / 3.0;
+                    let corner_x = _left_bound + (x + hex_radius * angle.cos()) / screen_size.0;
+                    let corner_y = _lower_bound - (y + hex_radius * angle.sin()) / screen_size.1;
+                    if k == 0 {
+                        cr.move_to(corner_x, corner_y);
+                    } else {
+                        cr.line_to(corner_x, corner_y);
+                    }
+                }
+                cr.close_path();
+                cr.fill();
+            }
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw colour bar if chosen
+            if show_legend == true && colour_min.is_finite() {
+                draw_colour_bar(cr, colour_min, colour_max, 0, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for HexbinPlot {
+    fn draw(&self) {
+        build_window(ChartType::Hexbin(self.clone()));
+    }
+}
+
+/*
+ * Helper function
+ */
+
+// Axial coordinates of the pointy topped hexagon containing the point (x, y), by rounding in cube coordinates.
+fn calc_hex_coords(x: f64, y: f64, hex_radius: f64) -> (i64, i64) {
+    let q = (3f64.sqrt() / 3.0 * x - y / 3.0) / hex_radius;
+    let r = (2.0 / 3.0 * y) / hex_radius;
+    let s = -q - r;
+
+    let mut round_q = q.round();
+    let mut round_r = r.round();
+    let round_s = s.round();
+
+    let q_diff = (round_q - q).abs();
+    let r_diff = (round_r - r).abs();
+    let s_diff = (round_s - s).abs();
+
+    if q_diff > r_diff && q_diff > s_diff {
+        round_q = -round_r - round_s;
+    } else if r_diff > s_diff {
+        round_r = -round_q - round_s;
+    }
+
+    (round_q as i64, round_r as i64)
+}
@@ -10,6 +10,7 @@ use chart_builder::charts::*;
 #[derive(Clone)]
 pub struct Histogram {
     data: Vec<f64>,
+    show_density: bool,
     pub chart_prop: ChartProp,
     pub axis_prop: AxisProp,
 }
@@ -33,12 +34,20 @@ impl Histogram {
 
         Histogram {
             data: new_data,
+            show_density: false,
             chart_prop: ChartProp::new(chart_title, &axis_type),
             axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
         }
     }
+    /// Set if a kernel density estimate curve should be drawn over the bars, scaled to the frequencies (false by default).
+    ///
+    /// ```show_density``` is a boolean value that should be set to true to show the density curve.
+    pub fn set_show_density(&mut self, show_density: bool) {
+        self.show_density = show_density;
+    }
     pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
         let data = self.data.clone();
+        let show_density = self.show_density;
 
         let chart_title = self.chart_prop.chart_title.clone();
 
@@ -110,8 +119,23 @@ impl Histogram {
             }
         }
 
-        // generate y axis properties from frequencies
-        let y_axis_props = calc_axis_props(&vec![frequencies.clone()], true, false);
+        // density at evenly spaced points across the x-axis, scaled from a proportion to a frequency of each group
+        let num_points = 200;
+        let mut density: Vec<f64> = Vec::new();
+        if show_density == true {
+            let bandwidth = calc_bandwidth(&data);
+            for i in 0..(num_points + 1) {
+                let val = x_axis_min + x_axis_range * (i as f64) / (num_points as f64);
+                density.push(calc_density(&data, bandwidth, val) * (data.len() as f64) * groups_range);
+            }
+        }
+
+        // generate y axis properties from frequencies (and density curve)
+        let mut y_axis_data = vec![frequencies.clone()];
+        if show_density == true {
+            y_axis_data.push(density.clone());
+        }
+        let y_axis_props = calc_axis_props(&y_axis_data, true, false);
         let y_axis_bounds = y_axis_props.0;
         let y_axis_scale = y_axis_props.1;
         let y_axis_min = y_axis_bounds.0;
@@ -148,6 +172,22 @@ impl Histogram {
                 cr.stroke();
             }
 
+            // density curve over the bars
+            if show_density == true {
+                for i in 0..density.len() {
+                    let x = _left_bound + (i as f64) / (num_points as f64) * _horizontal_scaling;
+                    let y = _lower_bound - get_percentage_in_bounds(density[i], y_axis_min, y_axis_max) * _vertical_scaling;
+                    if i == 0 {
+                        cr.move_to(x, y);
+                    } else {
+                        cr.line_to(x, y);
+                    }
+                }
+                set_nth_colour(cr, 1);
+                cr.set_line_width(0.003);
+                cr.stroke();
+            }
+
             // Chart Title
             draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
 
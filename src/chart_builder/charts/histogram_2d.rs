@@ -0,0 +1,222 @@
+//!
+
+
+use chart_builder::charts::*;
+
+// Largest number of bins used along each axis.
+const MAX_BINS: usize = 1000;
+
+/// Structure used for storing chart related data and the drawing of a 2D Histogram.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the density of a large number of (x, y) points by counting the points within rectangular bins, coloured on a colour scale.
+#[derive(Clone)]
+pub struct Histogram2D {
+    data_x: Vec<f64>,
+    data_y: Vec<f64>,
+    data_values: Vec<f64>,
+    bin_counts: (usize, usize),
+    bin_sizes: Option<(f64, f64)>,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl Histogram2D {
+    /// Creates a new instance of a Histogram2D.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_x``` is the number data placed on the x-axis of the chart, specifying horizontal positions of points.
+    ///
+    /// ```new_data_y``` is the number data placed on the y-axis of the chart, specifying vertical positions of points, with indexes corresponding to the same index in new_data_x.
+    ///
+    /// A colour bar is shown in the legend section of the window by default, this can be hidden using chart_prop.set_show_legend(false).
+    pub fn new(chart_title: String, new_data_x: Vec<f64>, new_data_y: Vec<f64>) -> Histogram2D {
+        let x_axis_props = calc_axis_props(&vec![new_data_x.clone()], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![new_data_y.clone()], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        let mut chart_prop = ChartProp::new(chart_title, &axis_type);
+        chart_prop.set_show_legend(true);
+
+        Histogram2D {
+            data_x: new_data_x,
+            data_y: new_data_y,
+            data_values: Vec::new(),
+            bin_counts: (20, 20),
+            bin_sizes: None,
+            chart_prop: chart_prop,
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the number of bins across the x-axis and up the y-axis (20 by 20 by default).
+    ///
+    /// ```x_bins``` and ```y_bins``` are the number of bins, each clamped between 1 and 1000.
+    pub fn set_bin_counts(&mut self, x_bins: usize, y_bins: usize) {
+        self.bin_counts = (x_bins.max(1).min(MAX_BINS), y_bins.max(1).min(MAX_BINS));
+        self.bin_sizes = None;
+    }
+    /// Sets the width and height of each bin in the units of the data, in place of the number of bins.
+    ///
+    /// ```x_size``` and ```y_size``` are positive f64 numbers, bins start from the lower bound of each axis.
+    /// Sizes that are not finite and greater than zero are ignored, and at most 1000 bins are used along each axis.
+    pub fn set_bin_sizes(&mut self, x_size: f64, y_size: f64) {
+        if !(x_size.is_finite() && x_size > 0.0 && y_size.is_finite() && y_size > 0.0) {
+            return;
+        }
+        self.bin_sizes = Some((x_size, y_size));
+    }
+    /// Sets a third value for each point, bins are coloured by the mean of these values in place of the number of points.
+    ///
+    /// ```new_data_values``` is the number data for each point, with indexes corresponding to the same index in the x and y data.
+    pub fn set_aggregate_values(&mut self, new_data_values: Vec<f64>) {
+        self.data_values = new_data_values;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        // Actual size of screen generate if legend section (colour bar) is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // size and number of bins in the units of the data
+        // bin sizes too small for the axis are widened so no more than MAX_BINS bins are used
+        let (mut x_bin_size, mut y_bin_size) = match self.bin_sizes {
+            Some(sizes) => sizes,
+            None => ((x_axis_max - x_axis_min) / (self.bin_counts.0 as f64), (y_axis_max - y_axis_min) / (self.bin_counts.1 as f64)),
+        };
+        x_bin_size = x_bin_size.max((x_axis_max - x_axis_min) / (MAX_BINS as f64));
+        y_bin_size = y_bin_size.max((y_axis_max - y_axis_min) / (MAX_BINS as f64));
+        let x_bins = ((x_axis_max - x_axis_min) / x_bin_size).ceil().max(1.0).min(MAX_BINS as f64) as usize;
+        let y_bins = ((y_axis_max - y_axis_min) / y_bin_size).ceil().max(1.0).min(MAX_BINS as f64) as usize;
+
+        // count points (and total their values) in each bin, points outside of the axis are ignored
+        let aggregate = self.data_values.len() > 0;
+        let mut counts: Vec<Vec<f64>> = vec![vec![0.0; y_bins]; x_bins];
+        let mut totals: Vec<Vec<f64>> = vec![vec![0.0; y_bins]; x_bins];
+        for i in 0..self.data_x.len().min(self.data_y.len()) {
+            let x_val = self.data_x[i];
+            let y_val = self.data_y[i];
+            if !(x_val >= x_axis_min && x_val <= x_axis_max && y_val >= y_axis_min && y_val <= y_axis_max) {
+                continue;
+            }
+            // values on the upper bound are added to the last bin
+            let x_bin = (((x_val - x_axis_min) / x_bin_size).floor() as usize).min(x_bins - 1);
+            let y_bin = (((y_val - y_axis_min) / y_bin_size).floor() as usize).min(y_bins - 1);
+            counts[x_bin][y_bin] += 1.0;
+            totals[x_bin][y_bin] += self.data_values.get(i).cloned().unwrap_or(0.0);
+        }
+
+        // value shown by each bin, None for empty bins
+        let mut bin_values: Vec<Vec<Option<f64>>> = vec![vec![None; y_bins]; x_bins];
+        for a in 0..x_bins {
+            for b in 0..y_bins {
+                if counts[a][b] > 0.0 {
+                    bin_values[a][b] = Some(if aggregate == true { totals[a][b] / counts[a][b] } else { counts[a][b] });
+                }
+            }
+        }
+        let colour_min = bin_values.iter().fold(0./0., |cur_min: f64, col| col.iter().fold(cur_min, |m, v| match *v { Some(v) => m.min(v), None => m }));
+        let colour_max = bin_values.iter().fold(0./0., |cur_max: f64, col| col.iter().fold(cur_max, |m, v| match *v { Some(v) => m.max(v), None => m }));
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing 2D Histogram components
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            for a in 0..x_bins {
+                for b in 0..y_bins {
+                    if let Some(value) = bin_values[a][b] {
+                        let x_start = x_axis_min + x_bin_size * (a as f64);
+                        let y_start = y_axis_min + y_bin_size * (b as f64);
+                        // bins are cut off at the upper bound of each axis
+                        let x_end = (x_start + x_bin_size).min(x_axis_max);
+                        let y_end = (y_start + y_bin_size).min(y_axis_max);
+
+                        set_scale_colour(cr, get_percentage_in_bounds(value, colour_min, colour_max));
+                        cr.rectangle(get_x(x_start), get_y(y_end), get_x(x_end) - get_x(x_start), get_y(y_start) - get_y(y_end));
+                        cr.fill();
+                    }
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw colour bar if chosen
+            if show_legend == true && colour_min.is_finite() {
+                draw_colour_bar(cr, colour_min, colour_max, 0, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for Histogram2D {
+    fn draw(&self) {
+        build_window(ChartType::Hist2D(self.clone()));
+    }
+}
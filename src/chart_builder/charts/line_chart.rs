@@ -3,6 +3,19 @@
 
 use chart_builder::charts::*;
 
+/// Enum used to specify how the points of a line are joined.
+#[derive(Clone, Copy, PartialEq)]
+pub enum StepMode {
+    /// Points are joined by straight lines (default).
+    Linear,
+    /// The line steps to the value of a point at the start of the interval before it.
+    Pre,
+    /// The line steps to the value of a point at the end of the interval after it.
+    Post,
+    /// The line steps to the value of a point halfway between it and the previous point.
+    Mid,
+}
+
 /// Structure used for storing chart related data and the drawing of an Line Chart.
 ///
 /// Show trends over time or in categories, highlighting order.
@@ -11,6 +24,10 @@ use chart_builder::charts::*;
 pub struct LineChart {
     data_labels: Vec<String>,
     data: Vec<Vec<f64>>,
+    step_mode: StepMode,
+    y_lower_errors: Vec<Vec<f64>>,
+    y_upper_errors: Vec<Vec<f64>>,
+    error_cap_width: f64,
     pub chart_prop: ChartProp,
     pub axis_prop: AxisProp,
 }
@@ -38,10 +55,50 @@ impl LineChart {
         LineChart {
             data_labels: new_data_labels,
             data: new_data,
+            step_mode: StepMode::Linear,
+            y_lower_errors: Vec::new(),
+            y_upper_errors: Vec::new(),
+            error_cap_width: 0.02,
             chart_prop: ChartProp::new(chart_title, &axis_type),
             axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
         }
     }
+    /// Sets how the points of each line are joined (StepMode::Linear by default).
+    ///
+    /// ```step_mode``` is a StepMode value, use StepMode::Pre, StepMode::Post or StepMode::Mid
+    /// to draw steps between points, e.g. for state changes or cumulative counts.
+    pub fn set_step_mode(&mut self, step_mode: StepMode) {
+        self.step_mode = step_mode;
+    }
+    /// Sets symmetric errors on the y-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_errors``` is the number data for the size of the error either side of each point,
+    /// with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors(&mut self, new_errors: Vec<Vec<f64>>) {
+        self.set_y_errors_asymmetric(new_errors.clone(), new_errors);
+    }
+    /// Sets asymmetric errors on the y-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_lower_errors``` and ```new_upper_errors``` are the number data for the size of the error
+    /// below and above each point, with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors_asymmetric(&mut self, new_lower_errors: Vec<Vec<f64>>, new_upper_errors: Vec<Vec<f64>>) {
+        self.y_lower_errors = new_lower_errors;
+        self.y_upper_errors = new_upper_errors;
+
+        let y_axis_props = calc_error_axis_props(&self.data, &self.y_lower_errors, &self.y_upper_errors, false, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    /// Sets the width of the caps at the ends of error bars (0.02 by default).
+    ///
+    /// ```cap_width``` is a f64 number specifying the width as a proportion of the width of the window, 0.0 draws no caps.
+    pub fn set_error_cap_width(&mut self, cap_width: f64) {
+        self.error_cap_width = cap_width;
+    }
     pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
         let data_labels = self.data_labels.clone();
         let data_y = self.data.clone();
@@ -49,6 +106,12 @@ impl LineChart {
 
         let chart_title = self.chart_prop.chart_title.clone();
 
+        let y_lower_errors = self.y_lower_errors.clone();
+        let y_upper_errors = self.y_upper_errors.clone();
+        let error_cap_width = self.error_cap_width;
+
+        let step_mode = self.step_mode;
+
         let x_axis_title = self.axis_prop.x_axis_title.clone();
         let x_axis_scale = self.axis_prop.x_axis_scale;
 
@@ -128,19 +191,38 @@ impl LineChart {
                     cr.stroke();
                     cr.restore();
 
-                    // draw lines from current coordinate to the previous
+                    // draw error bar through (x,y)
+                    let lower_error = get_error(&y_lower_errors, j, i);
+                    let upper_error = get_error(&y_upper_errors, j, i);
+                    if lower_error != 0.0 || upper_error != 0.0 {
+                        draw_error_bar(cr, x,
+                            _lower_bound - (get_percentage_in_bounds(y_val - lower_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                            _lower_bound - (get_percentage_in_bounds(y_val + upper_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                            true, error_cap_width, h_scale, v_scale);
+                    }
+
+                    // draw lines from current coordinate to the previous, through any steps between them
                     if i != 0 {
-                        let x_len = (x - prev_x).abs();
-                        let y_len = (y - prev_y).abs();
+                        let mut line_points = vec![(prev_x, prev_y)];
+                        line_points.extend(get_step_points((prev_x, prev_y), (x, y), step_mode));
+                        line_points.push((x, y));
+
+                        for k in 1..line_points.len() {
+                            let x_len = (line_points[k].0 - line_points[k - 1].0).abs();
+                            let y_len = (line_points[k].1 - line_points[k - 1].1).abs();
+                            if x_len == 0.0 && y_len == 0.0 {
+                                continue;
+                            }
 
-                        // Trigonometry used to scale width of diagonal lines
-                        cr.set_line_width(0.005 *
-                            (((x_len/y_len).atan() / (PI / 2.0) * v_scale) +
-                            ((y_len/x_len).atan() / (PI / 2.0) * h_scale)));
+                            // Trigonometry used to scale width of diagonal lines
+                            cr.set_line_width(0.005 *
+                                (((x_len/y_len).atan() / (PI / 2.0) * v_scale) +
+                                ((y_len/x_len).atan() / (PI / 2.0) * h_scale)));
 
-                        cr.move_to(x, y);
-                        cr.line_to(prev_x, prev_y);
-                        cr.stroke();
+                            cr.move_to(line_points[k].0, line_points[k].1);
+                            cr.line_to(line_points[k - 1].0, line_points[k - 1].1);
+                            cr.stroke();
+                        }
                     }
 
                     prev_x = x;
@@ -155,7 +237,7 @@ impl LineChart {
             draw_x_axis_cat(cr, scalings,
                 &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
                 screen_size,
-                false);
+                false, 1);
             draw_y_axis_con(cr, scalings,
                 y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
                 screen_size);
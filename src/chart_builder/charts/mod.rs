@@ -5,6 +5,8 @@ use chart_builder::*;
 use chart_builder::window::*;
 use chart_builder::chart_prop::AxisType;
 use chart_builder::axis_prop::calc_axis_props;
+use chart_builder::axis_prop::calc_error_axis_props;
+use chart_builder::axis_prop::get_error;
 use chart_builder::axis_prop::calc_data_range;
 use chart_builder::axis_prop::percentile;
 use chart_builder::axis_drawer::*;
@@ -32,6 +34,56 @@ pub(in chart_builder) mod xy_scatter_plot;
 
 pub(in chart_builder) mod bubble_chart;
 
+pub(in chart_builder) mod contour_plot;
+
+
+pub(in chart_builder) mod violin_plot;
+
+pub(in chart_builder) mod candlestick_chart;
+
+pub(in chart_builder) mod gantt_chart;
+
+pub(in chart_builder) mod waterfall_chart;
+
+pub(in chart_builder) mod funnel_chart;
+
+pub(in chart_builder) mod polar_area_chart;
+
+pub(in chart_builder) mod polar_plot;
+
+pub(in chart_builder) mod stem_plot;
+
+pub(in chart_builder) mod lollipop_chart;
+
+pub(in chart_builder) mod sankey_chart;
+
+pub(in chart_builder) mod sunburst_chart;
+
+pub(in chart_builder) mod parallel_coordinates;
+
+pub(in chart_builder) mod scatter_matrix;
+
+pub(in chart_builder) mod histogram_2d;
+
+pub(in chart_builder) mod hexbin_plot;
+
+pub(in chart_builder) mod density_plot;
+
+pub(in chart_builder) mod ecdf_plot;
+
+pub(in chart_builder) mod qq_plot;
+
+pub(in chart_builder) mod gauge_chart;
+
+pub(in chart_builder) mod bullet_chart;
+
+pub(in chart_builder) mod calendar_heatmap;
+
+pub(in chart_builder) mod dendrogram;
+
+pub(in chart_builder) mod quiver_plot;
+
+pub(in chart_builder) mod function_plot;
 
 /*
  * Default colours - from a list of 20 distinct colours - values are rgb/255
@@ -61,6 +113,18 @@ pub(self) static COLOURS: [(f64, f64, f64); 20] = [
     (0.5019607843,0.5019607843,0.5019607843),   // Grey
 ];
 
+/*
+ * Colour scale used for continuous values - anchor colours sampled from the viridis colour map
+ */
+
+pub(self) static SCALE_COLOURS: [(f64, f64, f64); 5] = [
+    (0.267004, 0.004874, 0.329415),             // Dark Purple
+    (0.229739, 0.322361, 0.545706),             // Blue
+    (0.127568, 0.566949, 0.550556),             // Teal
+    (0.369214, 0.788888, 0.382914),             // Green
+    (0.993248, 0.906157, 0.143936),             // Yellow
+];
+
 /*
  * Get scales used drawing in proportions of the screen
  */
@@ -94,6 +158,102 @@ pub(self) fn set_nth_colour_opacity(cr: &Context, n: usize, opacity: f64) {
     cr.set_source_rgba(colour.0, colour.1, colour.2, opacity);
 }
 
+// Sets the source colour to the point (between 0 and 1) along the colour scale
+pub(self) fn set_scale_colour(cr: &Context, proportion: f64) {
+    let colour = get_scale_colour(proportion);
+    cr.set_source_rgb(colour.0, colour.1, colour.2);
+}
+
+pub(self) fn get_scale_colour(proportion: f64) -> (f64, f64, f64) {
+    // values outside of the scale (or not a number) are given the colour at the nearest end
+    let proportion = if proportion > 0.0 { proportion.min(1.0) } else { 0.0 };
+    let pos = proportion * ((SCALE_COLOURS.len() - 1) as f64);
+    let index = (pos.floor() as usize).min(SCALE_COLOURS.len() - 2);
+    let d = pos - (index as f64);
+    let lower = SCALE_COLOURS[index];
+    let upper = SCALE_COLOURS[index + 1];
+    (lower.0 + d * (upper.0 - lower.0), lower.1 + d * (upper.1 - lower.1), lower.2 + d * (upper.2 - lower.2))
+}
+
+// Groups in the order they first appear
+pub(self) fn get_distinct_groups(groups: &Vec<String>) -> Vec<String> {
+    let mut distinct_groups: Vec<String> = Vec::new();
+    for group in groups.iter() {
+        if !distinct_groups.contains(group) {
+            distinct_groups.push(group.clone());
+        }
+    }
+    distinct_groups
+}
+
+// Gets the corners of the steps between two points of a line, not including the points themselves.
+// No corners are returned for StepMode::Linear as the points are joined directly.
+pub(self) fn get_step_points(prev: (f64, f64), cur: (f64, f64), step_mode: StepMode) -> Vec<(f64, f64)> {
+    match step_mode {
+        StepMode::Linear => Vec::new(),
+        StepMode::Pre => vec![(prev.0, cur.1)],
+        StepMode::Post => vec![(cur.0, prev.1)],
+        StepMode::Mid => {
+            let mid_x = (prev.0 + cur.0) / 2.0;
+            vec![(mid_x, prev.1), (mid_x, cur.1)]
+        },
+    }
+}
+
+// Draws an error bar from start to end with a cap at each end, along a vertical line at x = position
+// (or a horizontal line at y = position if vertical is false).
+// cap_width is the length of each cap as a proportion of the width of the screen.
+pub(self) fn draw_error_bar(cr: &Context, position: f64, start: f64, end: f64, vertical: bool, cap_width: f64, h_scale: f64, v_scale: f64) {
+    cr.save();
+    cr.set_dash(&[], 0.0);
+    if vertical == true {
+        cr.set_line_width(0.002 * h_scale);
+        cr.move_to(position, start);
+        cr.line_to(position, end);
+        cr.stroke();
+
+        cr.set_line_width(0.002 * v_scale);
+        for &y in [start, end].iter() {
+            cr.move_to(position - cap_width / 2.0, y);
+            cr.line_to(position + cap_width / 2.0, y);
+        }
+        cr.stroke();
+    } else {
+        // caps are scaled to be the same length on screen as a vertical error bar's caps
+        let cap_height = cap_width * v_scale / h_scale;
+
+        cr.set_line_width(0.002 * v_scale);
+        cr.move_to(start, position);
+        cr.line_to(end, position);
+        cr.stroke();
+
+        cr.set_line_width(0.002 * h_scale);
+        for &x in [start, end].iter() {
+            cr.move_to(x, position - cap_height / 2.0);
+            cr.line_to(x, position + cap_height / 2.0);
+        }
+        cr.stroke();
+    }
+    cr.restore();
+}
+
+// Draws a filled arrow head with its tip at (x, y) pointing in the direction of angle (radians).
+// The head is drawn in a scaled space so that it keeps its shape in a rectangular screen.
+pub(self) fn draw_arrow_head(cr: &Context, x: f64, y: f64, angle: f64, size: f64, h_scale: f64, v_scale: f64) {
+    // convert angle into the scaled space
+    let angle = (angle.sin() / v_scale).atan2(angle.cos() / h_scale);
+    cr.save();
+    cr.translate(x, y);
+    cr.scale(h_scale, v_scale);
+    cr.rotate(angle);
+    cr.move_to(0.0, 0.0);
+    cr.line_to(- size, size * 0.5);
+    cr.line_to(- size, - size * 0.5);
+    cr.close_path();
+    cr.fill();
+    cr.restore();
+}
+
 pub(self) fn draw_title(cr: &Context, _left_bound: f64, _upper_bound: f64, h_scale: f64, v_scale: f64, chart_title: &String) {
     cr.set_source_rgb(0.0, 0.0, 0.0);
     cr.set_font_size(0.025);
@@ -146,3 +306,201 @@ pub(self) fn draw_legend(cr: &Context, legend_values: &Vec<String>, screen_size:
         cr.stroke();
     }
 }
+
+// Draws a colour scale in the legend section of the screen labelled from min to max.
+// If num_bands is 0 the scale is continuous, otherwise it is split into that many bands of solid colour.
+pub(self) fn draw_colour_bar(cr: &Context, min: f64, max: f64, num_bands: usize, screen_size: (f64, f64), legend_size: f64) {
+    let band_bounds: Vec<f64> = if num_bands == 0 { Vec::new() }
+        else { (0..(num_bands + 1)).map(|i| min + (max - min) * (i as f64) / (num_bands as f64)).collect() };
+    draw_colour_bar_bounded(cr, min, max, &band_bounds, screen_size, legend_size);
+}
+
+// Draws a colour scale split into a band of solid colour between each pair of band_bounds,
+// each band is of equal height and labelled with its actual bounds (which need not be evenly spaced).
+pub(self) fn draw_banded_colour_bar(cr: &Context, band_bounds: &Vec<f64>, screen_size: (f64, f64), legend_size: f64) {
+    let min = band_bounds.first().cloned().unwrap_or(0.0);
+    let max = band_bounds.last().cloned().unwrap_or(0.0);
+    draw_colour_bar_bounded(cr, min, max, band_bounds, screen_size, legend_size);
+}
+
+// Continuous if band_bounds has fewer than two values, otherwise banded with labels at each bound.
+fn draw_colour_bar_bounded(cr: &Context, min: f64, max: f64, band_bounds: &Vec<f64>, screen_size: (f64, f64), legend_size: f64) {
+    let mut h_scale = screen_size.1 / screen_size.0;
+    let mut v_scale = screen_size.0 / screen_size.1;
+
+    let scale_boundary = (screen_size.0 - legend_size) / screen_size.0;
+    let scale_width = legend_size / screen_size.0;
+
+    // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+    if h_scale < v_scale {
+        v_scale = 1.0;
+    } else {
+        h_scale = 1.0;
+    }
+
+    let bar_left = scale_boundary + scale_width * 0.1;
+    let bar_width = 0.04 * h_scale;
+    let bar_top = 0.2;
+    let bar_height = 0.6;
+
+    // draw the bar from the bottom (min) to the top (max)
+    let num_bands = if band_bounds.len() < 2 { 0 } else { band_bounds.len() - 1 };
+    let num_slices = if num_bands == 0 { 100 } else { num_bands };
+    let slice_height = bar_height / (num_slices as f64);
+    for i in 0..num_slices {
+        let proportion = if num_slices == 1 { 0.0 } else { (i as f64) / ((num_slices - 1) as f64) };
+        set_scale_colour(cr, proportion);
+        // slight overlap stops gaps showing between slices
+        cr.rectangle(bar_left, bar_top + bar_height - slice_height * ((i + 1) as f64), bar_width, slice_height * 1.05);
+        cr.fill();
+    }
+
+    // bar outline
+    cr.set_source_rgb(0.0, 0.0, 0.0);
+    cr.set_line_width(0.002 * h_scale);
+    cr.rectangle(bar_left, bar_top, bar_width, bar_height);
+    cr.stroke();
+
+    cr.set_font_size(0.018);
+    let mut font_matrix = cr.get_font_matrix();
+    font_matrix.scale(h_scale, v_scale);
+    cr.set_font_matrix(font_matrix);
+
+    let dps: usize;
+    if max.abs().max(min.abs()) <= 0.1 { dps = 4; }
+    else if max.abs().max(min.abs()) >= 100.0 { dps = 0; }
+    else { dps = 2; }
+
+    // label bands at their boundaries, a continuous bar is labelled at 5 evenly spaced positions
+    let num_labels = if num_bands == 0 { 5 } else { num_bands + 1 };
+    for i in 0..num_labels {
+        let proportion = (i as f64) / ((num_labels - 1) as f64);
+        let y = bar_top + bar_height - proportion * bar_height;
+        cr.move_to(bar_left + bar_width, y);
+        cr.rel_line_to(0.01 * h_scale, 0.0);
+        cr.stroke();
+
+        let label_value = if num_bands == 0 { min + (max - min) * proportion } else { band_bounds[i] };
+        let label_string = format!("{:.*}", dps, label_value);
+        let label_str = label_string.as_str();
+        let text_height = cr.text_extents(label_str).height;
+        cr.move_to(bar_left + bar_width + 0.015 * h_scale, y + text_height / 2.0);
+        cr.show_text(label_str);
+    }
+}
+
+// Gets the points where each ring of a polar chart meets each spoke, starting from the outer ring.
+// The first spoke points straight up and spokes continue clockwise.
+// Must be called with the origin translated to the centre of the chart.
+pub(self) fn calc_ring_points(cr: &Context, num_spokes: usize, num_delimiters: usize, outline_scale: f64, max_radius: f64) -> Vec<Vec<(f64, f64)>> {
+    use std::f64::consts::PI;
+
+    let mut shape_points: Vec<Vec<(f64, f64)>> = Vec::new();
+    for j in 0..num_delimiters {
+        shape_points.push(Vec::new());
+        for i in 0..num_spokes {
+            let radians = (i as f64) / (num_spokes as f64) * 2.0 * PI - PI / 2.0;
+            cr.arc(0.0, 0.0, max_radius - (j as f64) * outline_scale * max_radius, 0.0, radians);
+            let point = cr.get_current_point();
+            shape_points[j].push(point);
+        }
+    }
+    cr.new_path();
+    shape_points
+}
+
+// Draws the outline rings/scaling lines through the points from calc_ring_points.
+// If circular is true each ring is drawn as a circle, otherwise as straight lines between spokes.
+pub(self) fn draw_rings(cr: &Context, shape_points: &Vec<Vec<(f64, f64)>>, circular: bool) {
+    use std::f64::consts::PI;
+
+    cr.set_line_width(0.0015);
+    cr.set_source_rgba(0.0, 0.0, 0.0, 0.5);
+
+    for j in 0..shape_points.len() {
+        let start_x: f64 = shape_points[j][0].0;
+        let start_y: f64 = shape_points[j][0].1;
+
+        if circular == true {
+            cr.new_path();
+            cr.arc(0.0, 0.0, (start_x * start_x + start_y * start_y).sqrt(), 0.0, 2.0 * PI);
+            cr.stroke();
+            continue;
+        }
+
+        let mut prev_x: f64 = start_x; // initalisation as start not needed
+        let mut prev_y: f64 = start_y; // initalisation as start not needed
+
+        cr.move_to(start_x, start_y);
+        for i in 0..shape_points[j].len() {
+            let x = shape_points[j][i].0;
+            let y = shape_points[j][i].1;
+
+            if i != 0 {
+                cr.move_to(prev_x, prev_y);
+                cr.line_to(x, y);
+                cr.stroke();
+            }
+
+            prev_x = x;
+            prev_y = y;
+        }
+        // join back to start
+        cr.move_to(prev_x, prev_y);
+        cr.line_to(start_x, start_y);
+        cr.stroke();
+    }
+}
+
+// Draws a line from the outer ring to the centre along each spoke and a label beyond the end of each spoke.
+// label_offset is the fraction of the angle between spokes that each label is turned clockwise from its spoke.
+pub(self) fn draw_spoke_labels(cr: &Context, data_labels: &Vec<String>, max_radius: f64, text_radius: f64, label_offset: f64) {
+    use std::f64::consts::PI;
+
+    cr.set_font_size(0.024);
+    for i in 0..data_labels.len() {
+        let radians = (i as f64) / (data_labels.len() as f64) * 2.0 * PI - PI / 2.0;
+
+        // draw line to center from outter points
+        cr.set_source_rgba(0.0, 0.0, 0.0, 0.5);
+        cr.arc(0.0, 0.0, max_radius, 0.0, radians);
+        let point = cr.get_current_point();
+        cr.new_path();
+        cr.move_to(point.0, point.1);
+        cr.line_to(0.0, 0.0);
+        cr.stroke();
+
+        // draw text on outter point
+        let radians = radians + label_offset / (data_labels.len() as f64) * 2.0 * PI;
+        cr.set_source_rgb(0.0, 0.0, 0.0);
+        cr.arc(0.0, 0.0, text_radius, 0.0, radians);
+        let point = cr.get_current_point();
+        let point_str = data_labels[i].as_str();
+        let text_width = cr.text_extents(point_str).width;
+        let text_height = cr.text_extents(point_str).height;
+        cr.rel_move_to(text_width * (point.0 - 0.5), text_height / 2.0);
+        cr.show_text(point_str);
+        cr.new_path();
+    }
+}
+
+// Draws the value of each ring to the left of where it meets the first spoke.
+pub(self) fn draw_ring_values(cr: &Context, shape_points: &Vec<Vec<(f64, f64)>>, outline_min: f64, outline_max: f64, outline_scale: f64, _horizontal_scaling: f64) {
+    cr.set_source_rgb(0.0, 0.0, 0.0);
+    cr.set_font_size(0.016);
+    let dps: usize;
+    if outline_max >= 100.0 || outline_min <= -100.0 { dps = 0; } else { dps = 2; }
+    for j in 0..shape_points.len() {
+        let num = outline_max - ((outline_max - outline_min) * outline_scale * (j as f64));
+        let num_string = format!("{:.*}", dps, num).to_string();
+        let num_str = num_string.as_str();
+        let text_width = cr.text_extents(num_str).width;
+        let text_height = cr.text_extents(num_str).height;
+
+        let x = shape_points[j][0].0;
+        let y = shape_points[j][0].1;
+
+        cr.move_to(x - text_width - 0.012 * _horizontal_scaling, y + text_height * 0.5);
+        cr.show_text(num_str);
+    }
+}
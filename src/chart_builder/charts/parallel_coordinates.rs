@@ -0,0 +1,250 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Parallel Coordinates chart.
+///
+/// Shows multivariate data with a vertical axis for each dimension, each record drawn as a line joining its values on every axis.
+#[derive(Clone)]
+pub struct ParallelCoordinates {
+    dimension_labels: Vec<String>,
+    data: Vec<Vec<f64>>,
+    groups: Vec<String>,
+    colour_values: Vec<f64>,
+    axis_order: Vec<usize>,
+    inverted_axes: Vec<usize>,
+    pub chart_prop: ChartProp,
+}
+
+impl ParallelCoordinates {
+    /// Creates a new instance of a ParallelCoordinates chart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_dimension_labels``` contains the name of each dimension, placed under its axis.
+    ///
+    /// ```new_data``` is the number data for each dimension, each inner vector is a dimension (e.g. a column of a CSV file)
+    /// named by the same index in new_dimension_labels. Values at the same index in each dimension form a record.
+    pub fn new(chart_title: String, new_dimension_labels: Vec<String>, new_data: Vec<Vec<f64>>) -> ParallelCoordinates {
+        let axis_type: AxisType = AxisType::NoAxis;
+        let axis_order: Vec<usize> = (0..new_data.len()).collect();
+
+        ParallelCoordinates {
+            dimension_labels: new_dimension_labels,
+            data: new_data,
+            groups: Vec::new(),
+            colour_values: Vec::new(),
+            axis_order: axis_order,
+            inverted_axes: Vec::new(),
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    /// Sets the group of each record, records in the same group are drawn in the same colour.
+    ///
+    /// ```new_groups``` contains the name of the group of each record, with indexes corresponding to the same index in each dimension.
+    ///
+    /// The names of the groups are used as the legend values and the legend is shown.
+    pub fn set_groups(&mut self, new_groups: Vec<String>) {
+        self.groups = new_groups;
+        self.colour_values = Vec::new();
+        let legend_values = get_distinct_groups(&self.groups);
+        self.chart_prop.set_legend_values(legend_values);
+        self.chart_prop.set_show_legend(true);
+    }
+    /// Sets a value for each record that its colour is taken from on a colour scale, in place of groups.
+    ///
+    /// ```new_colour_values``` contains the value of each record, with indexes corresponding to the same index in each dimension.
+    ///
+    /// A colour bar is shown in the legend section.
+    pub fn set_colour_values(&mut self, new_colour_values: Vec<f64>) {
+        self.colour_values = new_colour_values;
+        self.groups = Vec::new();
+        self.chart_prop.set_show_legend(true);
+    }
+    /// Sets the order of the axes from left to right.
+    ///
+    /// ```new_axis_order``` contains the indexes of the dimensions in the order they are drawn, dimensions left out are not drawn.
+    pub fn set_axis_order(&mut self, new_axis_order: Vec<usize>) {
+        self.axis_order = new_axis_order.into_iter().filter(|&d| d < self.data.len()).collect();
+    }
+    /// Sets which axes are inverted, with their largest value at the bottom (none by default).
+    ///
+    /// ```new_inverted_axes``` contains the indexes of the dimensions with inverted axes.
+    pub fn set_inverted_axes(&mut self, new_inverted_axes: Vec<usize>) {
+        self.inverted_axes = new_inverted_axes;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let dimension_labels = self.dimension_labels.clone();
+        let data = self.data.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let axis_order = self.axis_order.clone();
+        let inverted_axes = self.inverted_axes.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // each axis has its own range
+        let axis_props: Vec<((f64, f64), f64)> = data.iter().map(|d| calc_data_range(&vec![d.clone()], false, 0.8, 0.08, 0.2)).collect();
+
+        let num_records = data.iter().fold(0, |cur_max, d| cur_max.max(d.len()));
+
+        // colour of each record from its group or its value on the colour scale
+        let use_colour_scale = self.colour_values.len() > 0;
+        let colour_min = self.colour_values.iter().fold(0./0., |cur_min: f64, &x| cur_min.min(x));
+        let colour_max = self.colour_values.iter().fold(0./0., |cur_max: f64, &x| cur_max.max(x));
+        let distinct_groups = get_distinct_groups(&self.groups);
+        let mut colours: Vec<(f64, f64, f64)> = Vec::new();
+        for i in 0..num_records {
+            if use_colour_scale == true {
+                let value = self.colour_values.get(i).cloned().unwrap_or(colour_min);
+                colours.push(get_scale_colour(get_percentage_in_bounds(value, colour_min, colour_max)));
+            } else if i < self.groups.len() {
+                let index = distinct_groups.iter().position(|g| *g == self.groups[i]).unwrap();
+                colours.push(COLOURS[index % COLOURS.len()]);
+            } else {
+                colours.push(COLOURS[0]);
+            }
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Parallel Coordinates components
+
+            let axis_interval = if axis_order.len() > 1 { _horizontal_scaling / ((axis_order.len() - 1) as f64) } else { 0.0 };
+            let get_x = |k: usize| if axis_order.len() > 1 { _left_bound + axis_interval * (k as f64) } else { _left_bound + _horizontal_scaling / 2.0 };
+            let get_y = |d: usize, val: f64| {
+                let axis_min = (axis_props[d].0).0;
+                let axis_max = (axis_props[d].0).1;
+                let percentage = get_percentage_in_bounds(val, axis_min, axis_max);
+                let percentage = if inverted_axes.contains(&d) { 1.0 - percentage } else { percentage };
+                _lower_bound - percentage * _vertical_scaling
+            };
+
+            // Draw a line for each record through each axis
+            cr.set_line_width(0.002);
+            cr.set_line_join(cairo::LineJoin::Round);
+            for i in 0..num_records {
+                let colour = colours[i];
+                cr.set_source_rgba(colour.0, colour.1, colour.2, 0.6);
+                let mut started = false;
+                for k in 0..axis_order.len() {
+                    let d = axis_order[k];
+                    if let Some(&val) = data[d].get(i) {
+                        if started == false {
+                            cr.move_to(get_x(k), get_y(d, val));
+                            started = true;
+                        } else {
+                            cr.line_to(get_x(k), get_y(d, val));
+                        }
+                    }
+                }
+                cr.stroke();
+            }
+
+            cr.set_font_size(0.016);
+            let mut font_matrix = cr.get_font_matrix();
+            font_matrix.scale(h_scale, v_scale);
+            cr.set_font_matrix(font_matrix);
+
+            // Draw each axis with delimiters, numbers and the name of its dimension
+            for k in 0..axis_order.len() {
+                let d = axis_order[k];
+                let x = get_x(k);
+                let axis_min = (axis_props[d].0).0;
+                let axis_max = (axis_props[d].0).1;
+                let axis_scale = axis_props[d].1;
+
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.set_line_width(0.002 * h_scale);
+                cr.move_to(x, _lower_bound);
+                cr.line_to(x, _upper_bound);
+                cr.stroke();
+
+                let dps: usize;
+                if axis_max.abs().max(axis_min.abs()) <= 0.1 { dps = 4; }
+                else if axis_max.abs().max(axis_min.abs()) >= 100.0 { dps = 0; }
+                else { dps = 2; }
+
+                let num_delimiters = (1.0 / axis_scale).round() as usize;
+                cr.set_line_width(0.002 * v_scale);
+                for j in 0..(num_delimiters + 1) {
+                    let val = axis_min + (axis_max - axis_min) * axis_scale * (j as f64);
+                    let y = get_y(d, val);
+                    cr.move_to(x - 0.006 * h_scale, y);
+                    cr.line_to(x, y);
+                    cr.stroke();
+
+                    let num_string = format!("{:.*}", dps, val);
+                    let num_str = num_string.as_str();
+                    let text_width = cr.text_extents(num_str).width;
+                    let text_height = cr.text_extents(num_str).height;
+                    cr.move_to(x - 0.008 * h_scale - text_width, y + text_height / 2.0);
+                    cr.show_text(num_str);
+                }
+
+                if let Some(label) = dimension_labels.get(d) {
+                    let label_str = label.as_str();
+                    let text_width = cr.text_extents(label_str).width;
+                    let text_height = cr.text_extents(label_str).height;
+                    cr.move_to(x - text_width / 2.0, _lower_bound + 0.02 * v_scale + text_height);
+                    cr.show_text(label_str);
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend (or colour bar) if chosen
+            if show_legend == true {
+                if use_colour_scale == true {
+                    draw_colour_bar(cr, colour_min, colour_max, 0, screen_size, legend_size);
+                } else {
+                    draw_legend(cr, &legend_values, screen_size, legend_size);
+                }
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for ParallelCoordinates {
+    fn draw(&self) {
+        build_window(ChartType::ParallelCoordinates(self.clone()));
+    }
+}
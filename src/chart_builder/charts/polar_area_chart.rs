@@ -0,0 +1,169 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Polar Area Chart (also known as a Rose or Nightingale Chart).
+///
+/// Shows values of categories as wedges of equal angle whose radius encodes the value, useful for cyclical categories such as months.
+#[derive(Clone)]
+pub struct PolarAreaChart {
+    data_labels: Vec<String>,
+    data: Vec<Vec<f64>>,
+    pub chart_prop: ChartProp,
+}
+
+impl PolarAreaChart {
+    /// Creates a new instance of a PolarAreaChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` specifies the labels placed by each wedge of the chart (each string is a wedge).
+    ///
+    /// ```new_data``` is the number data quanities for each wedge of the chart corresponding to the labels with the same index in new_data_labels.
+    /// Each inner vector represents a series, with multiple series stacked outwards from the centre.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data: Vec<Vec<f64>>) -> PolarAreaChart {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        PolarAreaChart {
+            data_labels: new_data_labels,
+            data: new_data,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let data = self.data.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // series are stacked, so the outer edge of each wedge is the running total of the series up to it
+        let mut stacked_data: Vec<Vec<f64>> = Vec::new();
+        for j in 0..data.len() {
+            let mut stacked: Vec<f64> = Vec::new();
+            for i in 0..data_labels.len() {
+                let below = if j == 0 { 0.0 } else { stacked_data[j - 1][i] };
+                stacked.push(below + data[j].get(i).cloned().unwrap_or(0.0).max(0.0));
+            }
+            stacked_data.push(stacked);
+        }
+
+        // calculate properties for scaling used in outline
+        let outline_prop = calc_data_range(&stacked_data, true, 0.7, 0.12, 0.25);
+        let outline_min = (outline_prop.0).0;
+        let outline_max = (outline_prop.0).1;
+        let outline_scale = outline_prop.1;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Polar Area chart components
+
+            let x = _left_bound + 0.5 * _horizontal_scaling;
+            let y = _lower_bound - 0.5 * _vertical_scaling;
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let max_radius = 0.45 * radius_scaling;
+            let text_radius = 0.5 * radius_scaling;
+
+            use std::f64::consts::PI;
+
+            cr.save();
+            cr.translate(x, y);
+
+            cr.scale(h_scale, v_scale);
+            cr.set_line_cap(cairo::LineCap::Round);
+
+            // draw wedges of each series from the centre outwards, each starting where the series below ended
+            let wedge_angle = 2.0 * PI / (data_labels.len() as f64);
+            for j in 0..stacked_data.len() {
+                for i in 0..data_labels.len() {
+                    let start_angle = (i as f64) * wedge_angle - PI / 2.0;
+                    let end_angle = start_angle + wedge_angle;
+                    let below = if j == 0 { outline_min } else { stacked_data[j - 1][i] };
+                    let inner_radius = get_percentage_in_bounds(below, outline_min, outline_max) * max_radius;
+                    let outer_radius = get_percentage_in_bounds(stacked_data[j][i], outline_min, outline_max) * max_radius;
+
+                    cr.new_path();
+                    cr.arc(0.0, 0.0, outer_radius, start_angle, end_angle);
+                    cr.arc_negative(0.0, 0.0, inner_radius, end_angle, start_angle);
+                    cr.close_path();
+                    set_nth_colour_opacity(cr, j, 0.85);
+                    cr.fill_preserve();
+                    cr.set_source_rgb(1.0, 1.0, 1.0);
+                    cr.set_line_width(0.003);
+                    cr.stroke();
+                }
+            }
+
+            let num_delimiters = ((1.0/outline_scale).trunc() as usize) + 1;
+
+            // get all points of rings/scaling lines and draw them over the wedges
+            let shape_points = calc_ring_points(cr, data_labels.len(), num_delimiters, outline_scale, max_radius);
+            draw_rings(cr, &shape_points, true);
+
+            // draw lines between wedges and text by the centre of each wedge
+            draw_spoke_labels(cr, &data_labels, max_radius, text_radius, 0.5);
+
+            // draw number labels
+            draw_ring_values(cr, &shape_points, outline_min, outline_max, outline_scale, _horizontal_scaling);
+
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for PolarAreaChart {
+    fn draw(&self) {
+        build_window(ChartType::PolarArea(self.clone()));
+    }
+}
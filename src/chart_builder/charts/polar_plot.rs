@@ -0,0 +1,264 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Polar Plot.
+///
+/// Shows values positioned by an angle and a distance from the centre, e.g. wind directions or antenna patterns.
+#[derive(Clone)]
+pub struct PolarPlot {
+    data_theta: Vec<Vec<f64>>,
+    data_r: Vec<Vec<f64>>,
+    radians: bool,
+    zero_direction: f64,
+    clockwise: bool,
+    show_marks: bool,
+    show_lines: bool,
+    pub chart_prop: ChartProp,
+}
+
+impl PolarPlot {
+    /// Creates a new instance of a PolarPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_theta``` is the number data specifying the angle of each point, in degrees unless set to radians.
+    /// Each inner vector represents a series.
+    ///
+    /// ```new_data_r``` is the number data specifying the distance of each point from the centre, with indexes corresponding to the same index in new_data_theta.
+    /// Only points (and series) with both an angle and a distance are drawn.
+    pub fn new(chart_title: String, new_data_theta: Vec<Vec<f64>>, new_data_r: Vec<Vec<f64>>) -> PolarPlot {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        PolarPlot {
+            data_theta: new_data_theta,
+            data_r: new_data_r,
+            radians: false,
+            zero_direction: 0.0,
+            clockwise: false,
+            show_marks: true,
+            show_lines: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    /// Set if the angle data is given in radians (false by default).
+    ///
+    /// ```radians``` is a boolean value that should be set to true if angles are in radians rather than degrees.
+    /// Angular grid lines are labelled in the same unit.
+    pub fn set_radians(&mut self, radians: bool) {
+        self.radians = radians;
+    }
+    /// Sets the direction in which an angle of zero points (0.0 by default, pointing right).
+    ///
+    /// ```zero_direction``` is the angle in degrees, measured anticlockwise from pointing right, e.g. 90.0 for zero to point up.
+    pub fn set_zero_direction(&mut self, zero_direction: f64) {
+        self.zero_direction = zero_direction;
+    }
+    /// Set if angles increase clockwise (false by default).
+    ///
+    /// ```clockwise``` is a boolean value that should be set to true for angles to increase clockwise, e.g. for compass bearings.
+    pub fn set_clockwise(&mut self, clockwise: bool) {
+        self.clockwise = clockwise;
+    }
+    /// Set if marks should be drawn at each point (true by default).
+    ///
+    /// ```show_marks``` is a boolean value that should be set to true to show a mark at each point.
+    pub fn set_show_marks(&mut self, show_marks: bool) {
+        self.show_marks = show_marks;
+    }
+    /// Set if lines should join the points of each series in order (false by default).
+    ///
+    /// ```show_lines``` is a boolean value that should be set to true to join points with lines.
+    pub fn set_show_lines(&mut self, show_lines: bool) {
+        self.show_lines = show_lines;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_theta = self.data_theta.clone();
+        let data_r = self.data_r.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let radians = self.radians;
+        let zero_direction = self.zero_direction;
+        let clockwise = self.clockwise;
+        let show_marks = self.show_marks;
+        let show_lines = self.show_lines;
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // calculate properties for scaling used in outline
+        let outline_prop = calc_data_range(&data_r, true, 0.7, 0.12, 0.25);
+        let outline_min = (outline_prop.0).0;
+        let outline_max = (outline_prop.0).1;
+        let outline_scale = outline_prop.1;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Polar plot components
+
+            let x = _left_bound + 0.5 * _horizontal_scaling;
+            let y = _lower_bound - 0.5 * _vertical_scaling;
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let max_radius = 0.45 * radius_scaling;
+            let text_radius = 0.48 * radius_scaling;
+            let mark_radius = 0.009;
+
+            use std::f64::consts::PI;
+
+            // angle on screen (clockwise from pointing right) of an angle in the data
+            let get_screen_angle = |theta: f64| {
+                let theta = if radians == true { theta } else { theta.to_radians() };
+                let theta = if clockwise == true { theta } else { - theta };
+                theta - zero_direction.to_radians()
+            };
+
+            cr.save();
+            cr.translate(x, y);
+
+            cr.scale(h_scale, v_scale);
+            cr.set_line_cap(cairo::LineCap::Round);
+
+            let num_delimiters = ((1.0/outline_scale).trunc() as usize) + 1;
+
+            // get points of rings/scaling lines on a single spoke and draw them as circles
+            let shape_points = calc_ring_points(cr, 1, num_delimiters, outline_scale, max_radius);
+            draw_rings(cr, &shape_points, true);
+
+            // draw angular grid lines every 30 degrees with labels in the unit of the data
+            cr.set_font_size(0.022);
+            for i in 0..12 {
+                let theta = if radians == true { (i as f64) * PI / 6.0 } else { (i as f64) * 30.0 };
+                let angle = get_screen_angle(theta);
+
+                cr.set_source_rgba(0.0, 0.0, 0.0, 0.5);
+                cr.move_to(0.0, 0.0);
+                cr.line_to(angle.cos() * max_radius, angle.sin() * max_radius);
+                cr.stroke();
+
+                let label_string = if radians == true { format_pi_fraction(i, 6) } else { format!("{}°", i * 30) };
+                let label_str = label_string.as_str();
+                let text_width = cr.text_extents(label_str).width;
+                let text_height = cr.text_extents(label_str).height;
+                // labels are pushed outwards so that they never overlap the outer ring
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.move_to(
+                    angle.cos() * text_radius + (angle.cos() - 1.0) * text_width / 2.0,
+                    angle.sin() * text_radius + (angle.sin() + 1.0) * text_height / 2.0);
+                cr.show_text(label_str);
+            }
+
+            // draw data of each series, series without both angles and distances are not drawn
+            for j in 0..data_theta.len().min(data_r.len()) {
+                let mut points: Vec<(f64, f64)> = Vec::new();
+                for i in 0..data_theta[j].len().min(data_r[j].len()) {
+                    let val_radius = get_percentage_in_bounds(data_r[j][i], outline_min, outline_max).max(0.0) * max_radius;
+                    let angle = get_screen_angle(data_theta[j][i]);
+                    points.push((angle.cos() * val_radius, angle.sin() * val_radius));
+                }
+
+                set_nth_colour(cr, j);
+
+                if show_lines == true && points.len() > 1 {
+                    cr.set_line_width(0.005);
+                    cr.move_to(points[0].0, points[0].1);
+                    for i in 1..points.len() {
+                        cr.line_to(points[i].0, points[i].1);
+                    }
+                    cr.stroke();
+                }
+
+                if show_marks == true {
+                    for i in 0..points.len() {
+                        cr.new_path();
+                        cr.arc(points[i].0, points[i].1, mark_radius, 0.0, 2.0 * PI);
+                        cr.fill();
+                    }
+                }
+            }
+
+            // draw radial tick labels
+            draw_ring_values(cr, &shape_points, outline_min, outline_max, outline_scale, _horizontal_scaling);
+
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for PolarPlot {
+    fn draw(&self) {
+        build_window(ChartType::Polar(self.clone()));
+    }
+}
+
+// Formats numerator/denominator of pi in its simplest form, e.g. (2, 6) gives "π/3".
+fn format_pi_fraction(numerator: usize, denominator: usize) -> String {
+    if numerator == 0 {
+        return String::from("0");
+    }
+    let mut a = numerator;
+    let mut b = denominator;
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    let numerator = numerator / a;
+    let denominator = denominator / a;
+
+    let numerator_string = if numerator == 1 { String::new() } else { numerator.to_string() };
+    if denominator == 1 {
+        format!("{}π", numerator_string)
+    } else {
+        format!("{}π/{}", numerator_string, denominator)
+    }
+}
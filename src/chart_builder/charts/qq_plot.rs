@@ -0,0 +1,367 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Enum used to specify the theoretical distribution a sample is compared against.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Distribution {
+    /// Standard normal distribution (default).
+    Normal,
+    /// Uniform distribution between 0 and 1.
+    Uniform,
+    /// Exponential distribution with a rate of 1.
+    Exponential,
+}
+
+/// Structure used for storing chart related data and the drawing of a Q-Q Plot (or P-P Plot).
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the quantiles of a sample against the quantiles of a theoretical distribution (or another sample),
+/// points close to the reference line show the sample follows the same distribution.
+/// As a P-P plot it shows the cumulative probabilities of the sample against those of the reference instead.
+#[derive(Clone)]
+pub struct QQPlot {
+    data: Vec<f64>,
+    reference_data: Vec<f64>,
+    distribution: Distribution,
+    show_envelope: bool,
+    probability_plot: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl QQPlot {
+    /// Creates a new instance of a QQPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data``` is the number data of the sample, its quantiles are placed on the y-axis.
+    ///
+    /// The sample is compared against a normal distribution by default.
+    ///
+    /// Panics if ```new_data``` is empty.
+    pub fn new(chart_title: String, new_data: Vec<f64>) -> QQPlot {
+        if new_data.len() == 0 {
+            panic!("The sample must contain at least one value");
+        }
+        let distribution = Distribution::Normal;
+
+        let axis_props = calc_qq_axis_props(&new_data, &Vec::new(), distribution, false, false);
+        let x_axis_bounds = (axis_props.0).0;
+        let x_axis_scale = (axis_props.0).1;
+        let y_axis_bounds = (axis_props.1).0;
+        let y_axis_scale = (axis_props.1).1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        QQPlot {
+            data: new_data,
+            reference_data: Vec::new(),
+            distribution: distribution,
+            show_envelope: false,
+            probability_plot: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the theoretical distribution whose quantiles are placed on the x-axis (Distribution::Normal by default).
+    ///
+    /// ```distribution``` is a Distribution value, use Distribution::Uniform or Distribution::Exponential.
+    ///
+    /// The axis bounds are recalculated for the new quantiles.
+    pub fn set_distribution(&mut self, distribution: Distribution) {
+        self.distribution = distribution;
+        self.reference_data = Vec::new();
+        self.update_axis_props();
+    }
+    /// Sets a second sample whose quantiles are placed on the x-axis, in place of a theoretical distribution.
+    ///
+    /// ```new_reference_data``` is the number data of the second sample, which can be a different length to the first,
+    /// an empty sample compares against the theoretical distribution again.
+    ///
+    /// The axis bounds are recalculated for the new quantiles.
+    pub fn set_reference_sample(&mut self, new_reference_data: Vec<f64>) {
+        self.reference_data = new_reference_data;
+        self.update_axis_props();
+    }
+    /// Set if a 95% confidence envelope should be drawn around the reference line (false by default).
+    ///
+    /// ```show_envelope``` is a boolean value that should be set to true to show the envelope,
+    /// it is only drawn when comparing against a theoretical distribution.
+    pub fn set_show_envelope(&mut self, show_envelope: bool) {
+        self.show_envelope = show_envelope;
+        self.update_axis_props();
+    }
+    /// Set if a P-P plot should be drawn in place of a Q-Q plot (false by default).
+    ///
+    /// ```probability_plot``` is a boolean value that should be set to true to plot the cumulative probability of each value
+    /// in the reference distribution (or sample) against its cumulative probability in the sample, with both axes from 0 to 1.
+    /// Theoretical distributions are shifted and scaled to fit the lower and upper quartiles of the sample.
+    pub fn set_probability_plot(&mut self, probability_plot: bool) {
+        self.probability_plot = probability_plot;
+        self.update_axis_props();
+    }
+    fn update_axis_props(&mut self) {
+        let axis_props = calc_qq_axis_props(&self.data, &self.reference_data, self.distribution, self.show_envelope, self.probability_plot);
+        self.axis_prop.x_axis_bounds = (axis_props.0).0;
+        self.axis_prop.x_axis_scale = (axis_props.0).1;
+        self.axis_prop.y_axis_bounds = (axis_props.1).0;
+        self.axis_prop.y_axis_scale = (axis_props.1).1;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        let quantiles = if self.probability_plot == true { calc_probabilities(&self.data, &self.reference_data, self.distribution) }
+            else { calc_quantiles(&self.data, &self.reference_data, self.distribution) };
+        let theoretical = quantiles.0;
+        let sample = quantiles.1;
+        let envelope = if self.show_envelope == true { quantiles.2 } else { Vec::new() };
+        let (slope, intercept) = quantiles.3;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Q-Q (or P-P) Plot components
+            let get_x = |x_val: f64| _left_bound + (get_percentage_in_bounds(x_val, x_axis_min, x_axis_max) * _horizontal_scaling);
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            // reference line and envelope are cut off at the axis
+            cr.save();
+            cr.rectangle(_left_bound, _lower_bound - _vertical_scaling, _horizontal_scaling, _vertical_scaling);
+            cr.clip();
+
+            // envelope above the line from left to right, then below it back again
+            if envelope.len() > 0 {
+                cr.move_to(get_x(theoretical[0]), get_y(slope * theoretical[0] + intercept + envelope[0]));
+                for i in 1..theoretical.len() {
+                    cr.line_to(get_x(theoretical[i]), get_y(slope * theoretical[i] + intercept + envelope[i]));
+                }
+                for i in (0..theoretical.len()).rev() {
+                    cr.line_to(get_x(theoretical[i]), get_y(slope * theoretical[i] + intercept - envelope[i]));
+                }
+                cr.close_path();
+                set_nth_colour_opacity(cr, 1, 0.2);
+                cr.fill();
+            }
+
+            set_nth_colour(cr, 1);
+            cr.set_line_width(0.003);
+            cr.move_to(_left_bound, get_y(slope * x_axis_min + intercept));
+            cr.line_to(_right_bound, get_y(slope * x_axis_max + intercept));
+            cr.stroke();
+            cr.restore();
+
+            // a point for each pair of quantiles
+            use std::f64::consts::PI;
+            set_nth_colour(cr, 0);
+            for i in 0..theoretical.len() {
+                cr.save();
+                cr.translate(get_x(theoretical[i]), get_y(sample[i]));
+                cr.scale(h_scale, v_scale);
+                cr.arc(0.0, 0.0, 0.006, 0.0, 2.0 * PI);
+                cr.fill();
+                cr.restore();
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for QQPlot {
+    fn draw(&self) {
+        build_window(ChartType::QQ(self.clone()));
+    }
+}
+
+/*
+ * Helper functions
+ */
+
+// Quantile of a theoretical distribution at proportion p.
+fn get_quantile(distribution: Distribution, p: f64) -> f64 {
+    match distribution {
+        Distribution::Normal => calc_normal_quantile(p),
+        Distribution::Uniform => p,
+        Distribution::Exponential => -(1.0 - p).ln(),
+    }
+}
+
+// Cumulative probability of a theoretical distribution at x.
+fn get_probability(distribution: Distribution, x: f64) -> f64 {
+    match distribution {
+        Distribution::Normal => calc_normal_cdf(x),
+        Distribution::Uniform => x.max(0.0).min(1.0),
+        Distribution::Exponential => if x <= 0.0 { 0.0 } else { 1.0 - (-x).exp() },
+    }
+}
+
+// Probability density of a theoretical distribution at x.
+fn get_density(distribution: Distribution, x: f64) -> f64 {
+    use std::f64::consts::PI;
+    match distribution {
+        Distribution::Normal => (-0.5 * x * x).exp() / (2.0 * PI).sqrt(),
+        Distribution::Uniform => 1.0,
+        Distribution::Exponential => (-x).exp(),
+    }
+}
+
+// Pairs of (theoretical or reference, sample) quantiles, the half width of the 95% confidence envelope at each pair
+// and the (slope, intercept) of the reference line through the lower and upper quartiles.
+fn calc_quantiles(data: &Vec<f64>, reference_data: &Vec<f64>, distribution: Distribution)
+        -> (Vec<f64>, Vec<f64>, Vec<f64>, (f64, f64)) {
+    let mut sorted_data = data.clone();
+    sorted_data.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut sorted_reference = reference_data.clone();
+    sorted_reference.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let two_sample = sorted_reference.len() > 0;
+
+    let get_x_quantile = |p: f64| if two_sample == true { percentile(&sorted_reference, p) } else { get_quantile(distribution, p) };
+
+    // line through the lower and upper quartiles of both
+    let x_quartiles = (get_x_quantile(0.25), get_x_quantile(0.75));
+    let y_quartiles = (percentile(&sorted_data, 0.25), percentile(&sorted_data, 0.75));
+    let slope = (y_quartiles.1 - y_quartiles.0) / (x_quartiles.1 - x_quartiles.0);
+    let slope = if slope.is_finite() { slope } else { 0.0 };
+    let intercept = y_quartiles.0 - slope * x_quartiles.0;
+
+    // samples of different lengths are compared at the number of points in the smaller
+    let n = if two_sample == true { sorted_data.len().min(sorted_reference.len()) } else { sorted_data.len() };
+
+    let mut x_quantiles: Vec<f64> = Vec::new();
+    let mut y_quantiles: Vec<f64> = Vec::new();
+    let mut envelope: Vec<f64> = Vec::new();
+    for i in 0..n {
+        let p = ((i as f64) + 0.5) / (n as f64);
+        let x_quantile = get_x_quantile(p);
+        x_quantiles.push(x_quantile);
+        y_quantiles.push(percentile(&sorted_data, p));
+
+        // standard error of each order statistic, only known for theoretical distributions
+        if two_sample == false {
+            let standard_error = slope / get_density(distribution, x_quantile) * (p * (1.0 - p) / (n as f64)).sqrt();
+            envelope.push(1.96 * standard_error);
+        }
+    }
+
+    (x_quantiles, y_quantiles, envelope, (slope, intercept))
+}
+
+// Pairs of (theoretical or reference, sample) cumulative probabilities, the half width of the 95% confidence envelope
+// at each pair and the (slope, intercept) of the reference line, which is always y = x.
+fn calc_probabilities(data: &Vec<f64>, reference_data: &Vec<f64>, distribution: Distribution)
+        -> (Vec<f64>, Vec<f64>, Vec<f64>, (f64, f64)) {
+    let mut sorted_data = data.clone();
+    sorted_data.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let two_sample = reference_data.len() > 0;
+
+    // theoretical distribution is fitted to the sample using the line through the quartiles of the Q-Q plot
+    let (slope, intercept) = calc_quantiles(data, &Vec::new(), distribution).3;
+    let slope = if slope == 0.0 { 1.0 } else { slope };
+
+    let n = sorted_data.len();
+    let mut x_probabilities: Vec<f64> = Vec::new();
+    let mut y_probabilities: Vec<f64> = Vec::new();
+    let mut envelope: Vec<f64> = Vec::new();
+    for i in 0..n {
+        let p = ((i as f64) + 0.5) / (n as f64);
+        let x_probability = if two_sample == true {
+            (reference_data.iter().filter(|&&x| x <= sorted_data[i]).count() as f64) / (reference_data.len() as f64)
+        } else {
+            get_probability(distribution, (sorted_data[i] - intercept) / slope)
+        };
+        x_probabilities.push(x_probability);
+        y_probabilities.push(p);
+
+        if two_sample == false {
+            envelope.push(1.96 * (x_probability * (1.0 - x_probability) / (n as f64)).sqrt());
+        }
+    }
+
+    (x_probabilities, y_probabilities, envelope, (1.0, 0.0))
+}
+
+// Properties of both axes, with the y-axis including the envelope if shown, P-P plots are always from 0 to 1.
+fn calc_qq_axis_props(data: &Vec<f64>, reference_data: &Vec<f64>, distribution: Distribution, show_envelope: bool, probability_plot: bool)
+        -> (((f64, f64), f64), ((f64, f64), f64)) {
+    if probability_plot == true {
+        return (((0.0, 1.0), 0.2), ((0.0, 1.0), 0.2));
+    }
+
+    let quantiles = calc_quantiles(data, reference_data, distribution);
+    let (slope, intercept) = quantiles.3;
+
+    let mut y_axis_data = vec![quantiles.1];
+    let mut errors: Vec<Vec<f64>> = Vec::new();
+    if show_envelope == true && quantiles.2.len() > 0 {
+        // envelope is around the reference line at each theoretical quantile
+        y_axis_data.push(quantiles.0.iter().map(|&x| slope * x + intercept).collect());
+        errors = vec![Vec::new(), quantiles.2];
+    }
+
+    let x_axis_props = calc_axis_props(&vec![quantiles.0], false, true);
+    let y_axis_props = calc_error_axis_props(&y_axis_data, &errors, &errors, false, false);
+
+    (x_axis_props, y_axis_props)
+}
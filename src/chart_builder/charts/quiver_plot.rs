@@ -0,0 +1,245 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Quiver Plot.
+///
+/// Shows a field of vectors, such as velocities from a simulation, as arrows starting at each (x, y) point.
+#[derive(Clone)]
+pub struct QuiverPlot {
+    data_x: Vec<f64>,
+    data_y: Vec<f64>,
+    data_u: Vec<f64>,
+    data_v: Vec<f64>,
+    arrow_scale: Option<f64>,
+    colour_by_magnitude: bool,
+    show_key: bool,
+    key_magnitude: Option<f64>,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl QuiverPlot {
+    /// Creates a new instance of a QuiverPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_x``` and ```new_data_y``` are the number data for the positions arrows start from on the x-axis and y-axis.
+    ///
+    /// ```new_data_u``` and ```new_data_v``` are the number data for the horizontal and vertical components of each arrow,
+    /// with indexes corresponding to the same index in the positions.
+    pub fn new(chart_title: String, new_data_x: Vec<f64>, new_data_y: Vec<f64>, new_data_u: Vec<f64>, new_data_v: Vec<f64>) -> QuiverPlot {
+        let x_axis_props = calc_axis_props(&vec![new_data_x.clone()], false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let y_axis_props = calc_axis_props(&vec![new_data_y.clone()], false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        QuiverPlot {
+            data_x: new_data_x,
+            data_y: new_data_y,
+            data_u: new_data_u,
+            data_v: new_data_v,
+            arrow_scale: None,
+            colour_by_magnitude: false,
+            show_key: true,
+            key_magnitude: None,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the length of an arrow with a magnitude of 1, as a proportion of the width of the axis.
+    ///
+    /// ```arrow_scale``` is a positive f64 number, arrows keep their direction on screen whatever the bounds of each axis.
+    ///
+    /// By default the scale is chosen so the longest arrow is slightly shorter than the average spacing between points.
+    pub fn set_arrow_scale(&mut self, arrow_scale: f64) {
+        self.arrow_scale = Some(arrow_scale);
+    }
+    /// Set if arrows should be coloured on a colour scale by their magnitude (false by default).
+    ///
+    /// ```colour_by_magnitude``` is a boolean value that should be set to true to colour arrows by magnitude.
+    ///
+    /// This also shows the legend section of the window for a colour bar, which can be hidden using chart_prop.set_show_legend(false).
+    pub fn set_colour_by_magnitude(&mut self, colour_by_magnitude: bool) {
+        self.colour_by_magnitude = colour_by_magnitude;
+        self.chart_prop.set_show_legend(colour_by_magnitude);
+    }
+    /// Set if a key with a reference arrow and its magnitude should be drawn above the top right of the axis (true by default).
+    ///
+    /// ```show_key``` is a boolean value that should be set to false to hide the key.
+    pub fn set_show_key(&mut self, show_key: bool) {
+        self.show_key = show_key;
+    }
+    /// Sets the magnitude of the reference arrow in the key.
+    ///
+    /// ```key_magnitude``` is a positive f64 number, by default the largest magnitude rounded to one significant figure is used.
+    pub fn set_key_magnitude(&mut self, key_magnitude: f64) {
+        self.key_magnitude = Some(key_magnitude);
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_x = self.data_x.clone();
+        let data_y = self.data_y.clone();
+        let data_u = self.data_u.clone();
+        let data_v = self.data_v.clone();
+        let colour_by_magnitude = self.colour_by_magnitude;
+        let show_key = self.show_key;
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        // Actual size of screen generate if legend section (colour bar) is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        let num_arrows = data_x.len().min(data_y.len()).min(data_u.len()).min(data_v.len());
+        let magnitudes: Vec<f64> = (0..num_arrows).map(|i| (data_u[i].powf(2.0) + data_v[i].powf(2.0)).sqrt()).collect();
+        let min_magnitude = magnitudes.iter().fold(0./0., |cur_min: f64, &x| cur_min.min(x));
+        let max_magnitude = magnitudes.iter().fold(0./0., |cur_max: f64, &x| cur_max.max(x));
+
+        // length of an arrow with a magnitude of 1 in pixels
+        let plot_width = _horizontal_scaling * screen_size.0;
+        let arrow_scale = match self.arrow_scale {
+            Some(arrow_scale) => arrow_scale,
+            None => if max_magnitude > 0.0 { 0.9 / (num_arrows as f64).sqrt() / max_magnitude } else { 0.0 },
+        };
+        let pixel_scale = arrow_scale * plot_width;
+
+        // reference arrow of a round magnitude
+        let key_magnitude = match self.key_magnitude {
+            Some(key_magnitude) => key_magnitude,
+            None => {
+                let mag = (10.0 as f64).powf(max_magnitude.log10().floor());
+                (max_magnitude / mag).round() * mag
+            },
+        };
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Quiver Plot components
+
+            // draws an arrow from (x, y) with a length in pixels of (u, v), upwards for positive v
+            let draw_arrow = |x: f64, y: f64, u: f64, v: f64| {
+                let dx = u * pixel_scale / screen_size.0;
+                let dy = - v * pixel_scale / screen_size.1;
+                let length = (u.powf(2.0) + v.powf(2.0)).sqrt() * pixel_scale;
+                if length <= 0.0 {
+                    return;
+                }
+                // heads of short arrows are made smaller to keep the shape of the arrow
+                let head_size = (0.35 * length / screen_size.0.min(screen_size.1)).min(0.012);
+
+                cr.set_line_width(0.002);
+                cr.move_to(x, y);
+                cr.line_to(x + dx, y + dy);
+                cr.stroke();
+                draw_arrow_head(cr, x + dx, y + dy, dy.atan2(dx), head_size, h_scale, v_scale);
+            };
+
+            for i in 0..num_arrows {
+                if colour_by_magnitude == true {
+                    set_scale_colour(cr, get_percentage_in_bounds(magnitudes[i], min_magnitude, max_magnitude));
+                } else {
+                    set_nth_colour(cr, 0);
+                }
+                let x = _left_bound + (get_percentage_in_bounds(data_x[i], x_axis_min, x_axis_max) * _horizontal_scaling);
+                let y = _lower_bound - (get_percentage_in_bounds(data_y[i], y_axis_min, y_axis_max) * _vertical_scaling);
+                draw_arrow(x, y, data_u[i], data_v[i]);
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // reference arrow with its magnitude to the left, above the top right of the axis
+            if show_key == true && key_magnitude.is_finite() && key_magnitude > 0.0 {
+                let key_length = key_magnitude * pixel_scale / screen_size.0;
+                let key_y = _upper_bound * 0.75;
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                draw_arrow(_right_bound - key_length, key_y, key_magnitude, 0.0);
+
+                cr.set_font_size(0.018);
+                let mut font_matrix = cr.get_font_matrix();
+                font_matrix.scale(h_scale, v_scale);
+                cr.set_font_matrix(font_matrix);
+                // rounding removes floating point error from the automatic magnitude
+                let key_str = format!("{}", (key_magnitude * 10000.0).round() / 10000.0);
+                let text_width = cr.text_extents(key_str.as_str()).width;
+                let text_height = cr.text_extents(key_str.as_str()).height;
+                cr.move_to(_right_bound - key_length - text_width - 0.01 * h_scale, key_y + text_height / 2.0);
+                cr.show_text(key_str.as_str());
+            }
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw colour bar if chosen
+            if show_legend == true && colour_by_magnitude == true && min_magnitude.is_finite() {
+                draw_colour_bar(cr, min_magnitude, max_magnitude, 0, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for QuiverPlot {
+    fn draw(&self) {
+        build_window(ChartType::Quiver(self.clone()));
+    }
+}
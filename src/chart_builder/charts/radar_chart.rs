@@ -102,74 +102,12 @@ impl RadarChart {
 
             let num_delimiters = ((1.0/outline_scale).trunc() as usize) + 1;
 
-            cr.set_line_width(0.0015);
-            cr.set_source_rgba(0.0, 0.0, 0.0, 0.5);
-
-            // get all points of rings/scaling lines
-            let mut shape_points: Vec<Vec<(f64, f64)>> = Vec::new();
-            for j in 0..num_delimiters {
-                shape_points.push(Vec::new());
-                for i in 0..data_labels.len() {
-                    let radians = (i as f64) / (data_labels.len() as f64) * 2.0 * PI - PI / 2.0;
-                    cr.arc(0.0, 0.0, max_radius - (j as f64) * outline_scale * max_radius, 0.0, radians);
-                    let point = cr.get_current_point();
-                    shape_points[j].push(point);
-                }
-            }
-            cr.new_path();
-
-            // draw outline rings/scaling lines
-            for j in 0..shape_points.len() {
-                let start_x: f64 = shape_points[j][0].0;
-                let start_y: f64 = shape_points[j][0].1;
-                let mut prev_x: f64 = start_x; // initalisation as start not needed
-                let mut prev_y: f64 = start_y; // initalisation as start not needed
-
-                cr.move_to(start_x, start_y);
-                for i in 0..shape_points[j].len() {
-                    let x = shape_points[j][i].0;
-                    let y = shape_points[j][i].1;
-
-                    if i != 0 {
-                        cr.move_to(prev_x, prev_y);
-                        cr.line_to(x, y);
-                        cr.stroke();
-                    }
-
-                    prev_x = x;
-                    prev_y = y;
-                }
-                // join back to start
-                cr.move_to(prev_x, prev_y);
-                cr.line_to(start_x, start_y);
-                cr.stroke();
-            }
+            // get all points of rings/scaling lines and draw them
+            let shape_points = calc_ring_points(cr, data_labels.len(), num_delimiters, outline_scale, max_radius);
+            draw_rings(cr, &shape_points, false);
 
             // draw text and lines from outter to center
-            cr.set_font_size(0.024);
-            for i in 0..data_labels.len() {
-                let radians = (i as f64) / (data_labels.len() as f64) * 2.0 * PI - PI / 2.0;
-
-                // draw line to center from outter points
-                cr.set_source_rgba(0.0, 0.0, 0.0, 0.5);
-                cr.arc(0.0, 0.0, max_radius, 0.0, radians);
-                let point = cr.get_current_point();
-                cr.new_path();
-                cr.move_to(point.0, point.1);
-                cr.line_to(0.0, 0.0);
-                cr.stroke();
-
-                // draw text on outter point
-                cr.set_source_rgb(0.0, 0.0, 0.0);
-                cr.arc(0.0, 0.0, text_radius, 0.0, radians);
-                let point = cr.get_current_point();
-                let point_str = data_labels[i].as_str();
-                let text_width = cr.text_extents(point_str).width;
-                let text_height = cr.text_extents(point_str).height;
-                cr.rel_move_to(text_width * (point.0 - 0.5), text_height / 2.0);
-                cr.show_text(point_str);
-                cr.new_path();
-            }
+            draw_spoke_labels(cr, &data_labels, max_radius, text_radius, 0.0);
 
             // draw data rings/scaling lines
             cr.set_line_width(0.007);
@@ -210,23 +148,7 @@ impl RadarChart {
             }
 
             // draw number labels
-            cr.set_source_rgb(0.0, 0.0, 0.0);
-            cr.set_font_size(0.016);
-            let dps: usize;
-            if outline_max >= 100.0 || outline_min <= -100.0 { dps = 0; } else { dps = 2; }
-            for j in 0..num_delimiters {
-                let num = outline_max - ((outline_max - outline_min) * outline_scale * (j as f64));
-                let num_string = format!("{:.*}", dps, num).to_string();
-                let num_str = num_string.as_str();
-                let text_width = cr.text_extents(num_str).width;
-                let text_height = cr.text_extents(num_str).height;
-
-                let x = shape_points[j][0].0;
-                let y = shape_points[j][0].1;
-
-                cr.move_to(x - text_width - 0.012 * _horizontal_scaling, y + text_height * 0.5);
-                cr.show_text(num_str);
-            }
+            draw_ring_values(cr, &shape_points, outline_min, outline_max, outline_scale, _horizontal_scaling);
 
             cr.restore();
 
@@ -0,0 +1,236 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Sankey Chart.
+///
+/// Shows flows between stages, e.g. budget allocation or user journeys, with band widths proportional to the size of each flow.
+#[derive(Clone)]
+pub struct SankeyChart {
+    node_labels: Vec<String>,
+    links: Vec<(usize, usize, f64)>,
+    pub chart_prop: ChartProp,
+}
+
+impl SankeyChart {
+    /// Creates a new instance of a SankeyChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_node_labels``` contains the name of each node (stage) of the flow.
+    ///
+    /// ```new_links``` contains the flows between nodes as (source node index, target node index, value).
+    /// Nodes are placed in columns automatically, following the direction of the links.
+    pub fn new(chart_title: String, new_node_labels: Vec<String>, new_links: Vec<(usize, usize, f64)>) -> SankeyChart {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        SankeyChart {
+            node_labels: new_node_labels,
+            links: new_links,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let node_labels = self.node_labels.clone();
+        let links: Vec<(usize, usize, f64)> = self.links.iter().cloned()
+            .filter(|&(source, target, value)| source < node_labels.len() && target < node_labels.len() && source != target && value > 0.0)
+            .collect();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // Total of each node is the larger of the flows into and out of it
+        let num_nodes = node_labels.len();
+        let mut totals_in: Vec<f64> = vec![0.0; num_nodes];
+        let mut totals_out: Vec<f64> = vec![0.0; num_nodes];
+        for &(source, target, value) in links.iter() {
+            totals_out[source] += value;
+            totals_in[target] += value;
+        }
+        let totals: Vec<f64> = (0..num_nodes).map(|n| totals_in[n].max(totals_out[n])).collect();
+
+        let columns = calc_node_columns(num_nodes, &links);
+        let num_columns = columns.iter().fold(0, |cur_max, &c| cur_max.max(c)) + 1;
+
+        // Value to height scale shared by all nodes, limited by the column needing the most space
+        let node_gap = 0.03;
+        let mut value_scale = ::std::f64::INFINITY;
+        for c in 0..num_columns {
+            let column_nodes: Vec<usize> = (0..num_nodes).filter(|&n| columns[n] == c).collect();
+            let column_total = column_nodes.iter().fold(0.0, |acc, &n| acc + totals[n]);
+            let space = _vertical_scaling - node_gap * ((column_nodes.len() as f64) - 1.0).max(0.0);
+            if column_total > 0.0 {
+                value_scale = value_scale.min(space / column_total);
+            }
+        }
+        if !value_scale.is_finite() { value_scale = 0.0; }
+
+        // Position of the top of each node, each column is centred vertically
+        let mut node_tops: Vec<f64> = vec![0.0; num_nodes];
+        for c in 0..num_columns {
+            let column_nodes: Vec<usize> = (0..num_nodes).filter(|&n| columns[n] == c).collect();
+            let used = column_nodes.iter().fold(0.0, |acc, &n| acc + totals[n] * value_scale)
+                + node_gap * ((column_nodes.len() as f64) - 1.0).max(0.0);
+            let mut top = _upper_bound + (_vertical_scaling - used) / 2.0;
+            for &n in column_nodes.iter() {
+                node_tops[n] = top;
+                top += totals[n] * value_scale + node_gap;
+            }
+        }
+
+        // Vertical position of each band where it leaves its source and enters its target,
+        // bands are ordered by the position of the node at their other end so that they cross as little as possible
+        let mut link_starts: Vec<f64> = vec![0.0; links.len()];
+        let mut link_ends: Vec<f64> = vec![0.0; links.len()];
+        let mut order: Vec<usize> = (0..links.len()).collect();
+        order.sort_by(|&a, &b| node_tops[links[a].1].partial_cmp(&node_tops[links[b].1]).unwrap());
+        let mut offsets = node_tops.clone();
+        for &l in order.iter() {
+            link_starts[l] = offsets[links[l].0];
+            offsets[links[l].0] += links[l].2 * value_scale;
+        }
+        order.sort_by(|&a, &b| node_tops[links[a].0].partial_cmp(&node_tops[links[b].0]).unwrap());
+        let mut offsets = node_tops.clone();
+        for &l in order.iter() {
+            link_ends[l] = offsets[links[l].1];
+            offsets[links[l].1] += links[l].2 * value_scale;
+        }
+
+        let max_total = totals.iter().fold(0.0, |cur_max: f64, &x| cur_max.max(x));
+        let dps: usize = if max_total >= 100.0 { 0 } else { 2 };
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Sankey chart components
+
+            let node_width = 0.02 * h_scale;
+            let get_node_x = |n: usize| {
+                if num_columns == 1 { _left_bound }
+                else { _left_bound + (columns[n] as f64) / ((num_columns - 1) as f64) * (_horizontal_scaling - node_width) }
+            };
+
+            // Draw bands from each source to its target, coloured by source
+            for l in 0..links.len() {
+                let (source, target, value) = links[l];
+                let start_x = get_node_x(source) + node_width;
+                let end_x = get_node_x(target);
+                let mid_x = (start_x + end_x) / 2.0;
+                let width = value * value_scale;
+
+                cr.move_to(start_x, link_starts[l]);
+                cr.curve_to(mid_x, link_starts[l], mid_x, link_ends[l], end_x, link_ends[l]);
+                cr.line_to(end_x, link_ends[l] + width);
+                cr.curve_to(mid_x, link_ends[l] + width, mid_x, link_starts[l] + width, start_x, link_starts[l] + width);
+                cr.close_path();
+                set_nth_colour_opacity(cr, source % COLOURS.len(), 0.4);
+                cr.fill();
+            }
+
+            cr.set_font_size(0.02);
+            let mut font_matrix = cr.get_font_matrix();
+            font_matrix.scale(h_scale, v_scale);
+            cr.set_font_matrix(font_matrix);
+
+            // Draw nodes with their names and totals
+            for n in 0..num_nodes {
+                let x = get_node_x(n);
+                let height = totals[n] * value_scale;
+
+                set_nth_colour(cr, n % COLOURS.len());
+                cr.rectangle(x, node_tops[n], node_width, height);
+                cr.fill();
+
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                let label_string = format!("{} ({:.*})", node_labels[n], dps, totals[n]);
+                let label_str = label_string.as_str();
+                let text_width = cr.text_extents(label_str).width;
+                let text_height = cr.text_extents(label_str).height;
+                // labels of the last column are placed to the left of their nodes
+                if columns[n] + 1 == num_columns && num_columns > 1 {
+                    cr.move_to(x - 0.01 * h_scale - text_width, node_tops[n] + height / 2.0 + text_height / 2.0);
+                } else {
+                    cr.move_to(x + node_width + 0.01 * h_scale, node_tops[n] + height / 2.0 + text_height / 2.0);
+                }
+                cr.show_text(label_str);
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for SankeyChart {
+    fn draw(&self) {
+        build_window(ChartType::Sankey(self.clone()));
+    }
+}
+
+// Column of each node, nodes without incoming links start in the first column and each node is placed
+// one column after the furthest of its sources. Nodes without outgoing links are moved to the last column.
+fn calc_node_columns(num_nodes: usize, links: &Vec<(usize, usize, f64)>) -> Vec<usize> {
+    let mut columns: Vec<usize> = vec![0; num_nodes];
+    // longest path is at most num_nodes - 1 links, limiting passes stops cycles repeating forever
+    for _ in 0..num_nodes {
+        let mut changed = false;
+        for &(source, target, _) in links.iter() {
+            if columns[target] < columns[source] + 1 && columns[source] + 1 < num_nodes {
+                columns[target] = columns[source] + 1;
+                changed = true;
+            }
+        }
+        if changed == false { break; }
+    }
+
+    let last_column = columns.iter().fold(0, |cur_max, &c| cur_max.max(c));
+    for n in 0..num_nodes {
+        let has_outgoing = links.iter().any(|&(source, _, _)| source == n);
+        let has_incoming = links.iter().any(|&(_, target, _)| target == n);
+        if has_outgoing == false && has_incoming == true {
+            columns[n] = last_column;
+        }
+    }
+    columns
+}
@@ -0,0 +1,279 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Scatter Matrix (also known as a Pair Plot).
+///
+/// Shows the relationship between every pair of sets of values in a grid of scatter plots,
+/// with the distribution of each set of values along the diagonal.
+#[derive(Clone)]
+pub struct ScatterMatrix {
+    dimension_labels: Vec<String>,
+    data: Vec<Vec<f64>>,
+    groups: Vec<String>,
+    diagonal_density: bool,
+    pub chart_prop: ChartProp,
+}
+
+impl ScatterMatrix {
+    /// Creates a new instance of a ScatterMatrix.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_dimension_labels``` contains the name of each set of values, placed on the axes of its row and column.
+    ///
+    /// ```new_data``` is the number data for each set of values, each inner vector is a set of values (e.g. a column of a CSV file)
+    /// named by the same index in new_dimension_labels. Values at the same index in each set form a point.
+    pub fn new(chart_title: String, new_dimension_labels: Vec<String>, new_data: Vec<Vec<f64>>) -> ScatterMatrix {
+        let axis_type: AxisType = AxisType::Single;
+
+        ScatterMatrix {
+            dimension_labels: new_dimension_labels,
+            data: new_data,
+            groups: Vec::new(),
+            diagonal_density: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    /// Sets the group of each point, points in the same group are drawn in the same colour.
+    ///
+    /// ```new_groups``` contains the name of the group of each point, with indexes corresponding to the same index in each set of values.
+    ///
+    /// The names of the groups are used as the legend values and the legend is shown.
+    pub fn set_groups(&mut self, new_groups: Vec<String>) {
+        self.groups = new_groups;
+        let legend_values = get_distinct_groups(&self.groups);
+        self.chart_prop.set_legend_values(legend_values);
+        self.chart_prop.set_show_legend(true);
+    }
+    /// Set if density curves should be drawn on the diagonal in place of histograms (false by default).
+    ///
+    /// ```diagonal_density``` is a boolean value that should be set to true to show density curves.
+    pub fn set_diagonal_density(&mut self, diagonal_density: bool) {
+        self.diagonal_density = diagonal_density;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let dimension_labels = self.dimension_labels.clone();
+        let data = self.data.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let diagonal_density = self.diagonal_density;
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // axes are shared along rows and columns, with fewer delimiters to fit in each cell
+        let axis_props: Vec<((f64, f64), f64)> = data.iter().map(|d| calc_data_range(&vec![d.clone()], false, 0.8, 0.2, 0.5)).collect();
+
+        // index of the group of each point, from the order groups first appear
+        let distinct_groups = get_distinct_groups(&self.groups);
+        let num_groups = distinct_groups.len().max(1);
+        let num_points = data.iter().fold(0, |cur_max, d| cur_max.max(d.len()));
+        let mut group_index: Vec<usize> = Vec::new();
+        for i in 0..num_points {
+            if i < self.groups.len() {
+                group_index.push(distinct_groups.iter().position(|g| *g == self.groups[i]).unwrap());
+            } else {
+                group_index.push(0);
+            }
+        }
+
+        // Distribution of each set of values for each group, either histogram frequencies or density curves
+        let num_points_curve = 100;
+        let mut distributions: Vec<Vec<Vec<f64>>> = Vec::new();
+        for d in 0..data.len() {
+            let axis_min = (axis_props[d].0).0;
+            let axis_max = (axis_props[d].0).1;
+            let num_ranges = ((1.0 / axis_props[d].1).round() as usize * 2).max(1);
+
+            let mut group_distributions: Vec<Vec<f64>> = Vec::new();
+            for g in 0..num_groups {
+                let group_data: Vec<f64> = data[d].iter().enumerate()
+                    .filter(|&(i, _)| group_index[i] == g)
+                    .map(|(_, &x)| x)
+                    .collect();
+
+                let mut distribution: Vec<f64>;
+                if diagonal_density == true {
+                    distribution = Vec::new();
+                    if group_data.len() > 0 {
+                        let bandwidth = calc_bandwidth(&group_data);
+                        for j in 0..(num_points_curve + 1) {
+                            let val = axis_min + (axis_max - axis_min) * (j as f64) / (num_points_curve as f64);
+                            distribution.push(calc_density(&group_data, bandwidth, val));
+                        }
+                    }
+                } else {
+                    distribution = vec![0.0; num_ranges];
+                    for &val in group_data.iter() {
+                        let range = (get_percentage_in_bounds(val, axis_min, axis_max) * (num_ranges as f64)).floor();
+                        if range.is_finite() {
+                            distribution[(range.max(0.0) as usize).min(num_ranges - 1)] += 1.0;
+                        }
+                    }
+                }
+                group_distributions.push(distribution);
+            }
+            distributions.push(group_distributions);
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Scatter Matrix components
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let mark_radius = 0.004 * radius_scaling;
+
+            use std::f64::consts::PI;
+
+            let n = data.len();
+            let cell_gap = 0.015;
+            let cell_width = (_horizontal_scaling - cell_gap * h_scale * ((n as f64) - 1.0)) / (n as f64);
+            let cell_height = (_vertical_scaling - cell_gap * v_scale * ((n as f64) - 1.0)) / (n as f64);
+
+            // scalings of the cell in row r and column c, in the same form as the scalings of the whole chart
+            let get_cell_scalings = |r: usize, c: usize| {
+                let left = _left_bound + (cell_width + cell_gap * h_scale) * (c as f64);
+                let upper = _upper_bound + (cell_height + cell_gap * v_scale) * (r as f64);
+                (cell_width, cell_height, left, left + cell_width, upper + cell_height, upper)
+            };
+
+            for r in 0..n {
+                for c in 0..n {
+                    let cell_scalings = get_cell_scalings(r, c);
+                    let left = cell_scalings.2;
+                    let lower = cell_scalings.4;
+                    let upper = cell_scalings.5;
+
+                    // cell outline
+                    cr.set_source_rgba(0.0, 0.0, 0.0, 0.3);
+                    cr.set_line_width(0.0015);
+                    cr.rectangle(left, upper, cell_width, cell_height);
+                    cr.stroke();
+
+                    let x_min = (axis_props[c].0).0;
+                    let x_max = (axis_props[c].0).1;
+
+                    if r == c {
+                        // Distribution of values on the diagonal, each cell scaled to its own largest value
+                        let max_value = distributions[c].iter().fold(0.0, |cur_max: f64, dist| dist.iter().fold(cur_max, |m, &x| m.max(x)));
+                        if max_value <= 0.0 { continue; }
+                        for g in 0..distributions[c].len() {
+                            let distribution = &distributions[c][g];
+                            if distribution.len() == 0 { continue; }
+                            let interval = cell_width / (distribution.len() as f64);
+                            if diagonal_density == true {
+                                cr.move_to(left, lower);
+                                for j in 0..distribution.len() {
+                                    cr.line_to(
+                                        left + cell_width * (j as f64) / ((distribution.len() - 1) as f64),
+                                        lower - distribution[j] / max_value * cell_height * 0.9);
+                                }
+                                cr.line_to(left + cell_width, lower);
+                                cr.close_path();
+                                set_nth_colour_opacity(cr, g % COLOURS.len(), 0.4);
+                                cr.fill_preserve();
+                                set_nth_colour(cr, g % COLOURS.len());
+                                cr.set_line_width(0.002);
+                                cr.stroke();
+                            } else {
+                                set_nth_colour_opacity(cr, g % COLOURS.len(), if num_groups > 1 { 0.5 } else { 1.0 });
+                                for j in 0..distribution.len() {
+                                    cr.rectangle(
+                                        left + interval * (j as f64),
+                                        lower,
+                                        interval,
+                                        - distribution[j] / max_value * cell_height * 0.9);
+                                }
+                                cr.fill();
+                            }
+                        }
+                    } else {
+                        // Scatter of column values against row values
+                        let y_min = (axis_props[r].0).0;
+                        let y_max = (axis_props[r].0).1;
+                        for i in 0..data[c].len().min(data[r].len()) {
+                            let x = left + get_percentage_in_bounds(data[c][i], x_min, x_max) * cell_width;
+                            let y = lower - get_percentage_in_bounds(data[r][i], y_min, y_max) * cell_height;
+
+                            set_nth_colour_opacity(cr, group_index[i] % COLOURS.len(), 0.7);
+                            cr.save();
+                            cr.translate(x, y);
+                            cr.scale(h_scale, v_scale);
+                            cr.arc(0.0, 0.0, mark_radius, 0.0, 2.0 * PI);
+                            cr.fill();
+                            cr.restore();
+                        }
+                    }
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw shared axes along the bottom row and left column
+            let empty_title = String::new();
+            for k in 0..n {
+                let title = dimension_labels.get(k).unwrap_or(&empty_title);
+                draw_x_axis_con(cr, get_cell_scalings(n - 1, k),
+                    (axis_props[k].0).0, (axis_props[k].0).1, axis_props[k].1, 0.0, title,
+                    screen_size);
+                draw_y_axis_con(cr, get_cell_scalings(k, 0),
+                    (axis_props[k].0).0, (axis_props[k].0).1, axis_props[k].1, 0.0, title,
+                    screen_size);
+            }
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for ScatterMatrix {
+    fn draw(&self) {
+        build_window(ChartType::ScatterMatrix(self.clone()));
+    }
+}
@@ -159,7 +159,7 @@ impl StackedAreaChart {
             draw_x_axis_cat(cr, scalings,
                 &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
                 screen_size,
-                true);
+                true, 1);
             draw_y_axis_con(cr, scalings,
                 y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
                 screen_size);
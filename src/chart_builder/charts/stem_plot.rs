@@ -0,0 +1,192 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Stem Plot.
+///
+/// Shows discrete values (e.g. samples of a signal) as lines from a baseline to a mark at each value.
+#[derive(Clone)]
+pub struct StemPlot {
+    data_x: Vec<Vec<f64>>,
+    data_y: Vec<Vec<f64>>,
+    baseline: f64,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl StemPlot {
+    /// Creates a new instance of a StemPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_x``` is the number data placed on the x-axis of the chart, specifying horizontal positions of stems.
+    /// Each inner vector represents a series.
+    ///
+    /// ```new_data_y``` is the number data placed on the y-axis of the chart, specifying the values at the end of each stem,
+    /// with indexes corresponding to the same index in new_data_x.
+    pub fn new(chart_title: String, new_data_x: Vec<Vec<f64>>, new_data_y: Vec<Vec<f64>>) -> StemPlot {
+        let x_axis_props = calc_axis_props(&new_data_x, false, true);
+        let x_axis_bounds = x_axis_props.0;
+        let x_axis_scale = x_axis_props.1;
+
+        let mut y_axis_data = new_data_y.clone();
+        y_axis_data.push(vec![0.0]);
+        let y_axis_props = calc_axis_props(&y_axis_data, false, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if (x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0) && (y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0) { AxisType::Full }
+            else if x_axis_bounds.0 < 0.0 && x_axis_bounds.1 > 0.0 { AxisType::DoubleHorizontal }
+            else if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        StemPlot {
+            data_x: new_data_x,
+            data_y: new_data_y,
+            baseline: 0.0,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the value on the y-axis that stems are drawn from (0.0 by default).
+    ///
+    /// ```baseline``` is a f64 number, the y-axis bounds are recalculated to include it.
+    pub fn set_baseline(&mut self, baseline: f64) {
+        self.baseline = baseline;
+
+        let mut y_axis_data = self.data_y.clone();
+        y_axis_data.push(vec![baseline]);
+        let y_axis_props = calc_axis_props(&y_axis_data, false, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_x = self.data_x.clone();
+        let data_y = self.data_y.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let baseline = self.baseline;
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+        let x_axis_bounds: (f64, f64) = self.axis_prop.x_axis_bounds;
+        let x_axis_min = x_axis_bounds.0;
+        let x_axis_max = x_axis_bounds.1;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        // Actual size of screen generate if legend section is to be shown.
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Stem plot components
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let mark_radius = 0.008 * radius_scaling;
+
+            use std::f64::consts::PI;
+
+            let base_y = _lower_bound - (get_percentage_in_bounds(baseline, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            // baseline across the chart
+            cr.set_source_rgb(0.0, 0.0, 0.0);
+            cr.set_line_width(0.002 * v_scale);
+            cr.move_to(_left_bound, base_y);
+            cr.line_to(_right_bound, base_y);
+            cr.stroke();
+
+            for j in 0..data_x.len() {
+                set_nth_colour(cr, j);
+
+                for i in 0..data_x[j].len() {
+                    let x = _left_bound + (get_percentage_in_bounds(data_x[j][i], x_axis_min, x_axis_max) * _horizontal_scaling);
+                    let y = _lower_bound - (get_percentage_in_bounds(data_y[j][i], y_axis_min, y_axis_max) * _vertical_scaling);
+
+                    // stem from baseline to value
+                    cr.set_line_width(0.003 * h_scale);
+                    cr.move_to(x, base_y);
+                    cr.line_to(x, y);
+                    cr.stroke();
+
+                    // draw mark (round) at (x,y)
+                    cr.save();
+                    cr.translate(x, y);
+                    cr.scale(h_scale, v_scale);
+                    cr.arc(0.0, 0.0, mark_radius, 0.0, 2.0 * PI);
+                    cr.fill();
+                    cr.restore();
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_con(cr, scalings,
+                x_axis_min, x_axis_max, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, calc_zero_intercept(x_axis_min, x_axis_max), &y_axis_title,
+                screen_size);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for StemPlot {
+    fn draw(&self) {
+        build_window(ChartType::Stem(self.clone()));
+    }
+}
@@ -0,0 +1,239 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Sunburst Chart.
+///
+/// Show proportions of a hierarchy, each ring is a level of the hierarchy with child segments inside the span of their parent.
+#[derive(Clone)]
+pub struct SunburstChart {
+    data_labels: Vec<String>,
+    parents: Vec<Option<usize>>,
+    data: Vec<f64>,
+    pub chart_prop: ChartProp,
+}
+
+impl SunburstChart {
+    /// Creates a new instance of a SunburstChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` contains the name of each node of the tree.
+    ///
+    /// ```new_parents``` contains the index of the parent of each node, or None for nodes in the inner ring,
+    /// with indexes corresponding to the same index in new_data_labels.
+    ///
+    /// ```new_data``` is the number data for the value of each node, with indexes corresponding to the same index in new_data_labels.
+    /// A node with children takes the total of its children if this is larger than its own value.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_parents: Vec<Option<usize>>, new_data: Vec<f64>) -> SunburstChart {
+        let axis_type: AxisType = AxisType::NoAxis;
+
+        SunburstChart {
+            data_labels: new_data_labels,
+            parents: new_parents,
+            data: new_data,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+        }
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let legend_values = self.chart_prop.legend_values.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let mut screen_size = self.chart_prop.screen_size;
+        let show_legend = self.chart_prop.show_legend;
+        let legend_size = (screen_size.0 * 0.30).ceil();
+        screen_size.0 = if show_legend == false { screen_size.0 } else { screen_size.0 + legend_size };
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        // Scaling used dependant use of a legend
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        if show_legend == true {
+            scalings = get_legend_scale(screen_size, legend_size);
+        } else {
+            scalings = get_normal_scale();
+        }
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        let segments = calc_segments(&self.parents, &self.data);
+        let max_depth = segments.iter().fold(0, |cur_max, s| cur_max.max(s.depth + 1));
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Sunburst chart Components
+
+            let x = _left_bound + 0.5 * _horizontal_scaling;
+            let y = _lower_bound - 0.5 * _vertical_scaling;
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let max_radius = 0.45 * radius_scaling;
+            let min_radius = 0.10 * radius_scaling;
+            let ring_width = (max_radius - min_radius) / (max_depth.max(1) as f64);
+
+            use std::f64::consts::PI;
+
+            cr.save();
+            // Moving drawing origin to (x,y)
+            cr.translate(x, y);
+            // Scaling the current transformation matrix by different amounts in the X and Y directions.
+            // This is done to assure a circlular object in a rectangular screen.
+            cr.scale(h_scale, v_scale);
+
+            cr.set_font_size(0.018);
+            cr.set_line_width(0.003);
+
+            for n in 0..segments.len() {
+                let segment = &segments[n];
+                if segment.end_rad <= segment.start_rad {
+                    continue;
+                }
+                let start_rad = segment.start_rad * 2.0 * PI - PI / 2.0;
+                let end_rad = segment.end_rad * 2.0 * PI - PI / 2.0;
+                let inner_radius = min_radius + (segment.depth as f64) * ring_width;
+                let outer_radius = inner_radius + ring_width;
+
+                // Draw segment in the colour of its root, lightened further from the centre
+                cr.new_path();
+                cr.arc(0.0, 0.0, outer_radius, start_rad, end_rad);
+                cr.arc_negative(0.0, 0.0, inner_radius, end_rad, start_rad);
+                cr.close_path();
+                let colour = COLOURS[segment.root % COLOURS.len()];
+                let tint = ((segment.depth as f64) * 0.18).min(0.7);
+                cr.set_source_rgb(
+                    colour.0 + (1.0 - colour.0) * tint,
+                    colour.1 + (1.0 - colour.1) * tint,
+                    colour.2 + (1.0 - colour.2) * tint);
+                cr.fill_preserve();
+                cr.set_source_rgb(1.0, 1.0, 1.0);
+                cr.stroke();
+
+                // Label segment only if its name fits inside it
+                let label_str = data_labels.get(n).map(|label| label.as_str()).unwrap_or("");
+                let text_width = cr.text_extents(label_str).width;
+                let text_height = cr.text_extents(label_str).height;
+                let mid_radius = (inner_radius + outer_radius) / 2.0;
+                let mid_rad = (start_rad + end_rad) / 2.0;
+                let arc_length = mid_radius * (end_rad - start_rad);
+                // horizontal text needs the most room along the arc when the segment is at the left or right
+                let needed_width = text_width * mid_rad.sin().abs() + text_height * mid_rad.cos().abs();
+                let needed_height = text_width * mid_rad.cos().abs() + text_height * mid_rad.sin().abs();
+                if arc_length > needed_width * 1.2 && ring_width > needed_height * 1.1 {
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                    cr.move_to(mid_rad.cos() * mid_radius - text_width / 2.0, mid_rad.sin() * mid_radius + text_height / 2.0);
+                    cr.show_text(label_str);
+                }
+            }
+            cr.restore();
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw legend if chosen
+            if show_legend == true {
+                draw_legend(cr, &legend_values, screen_size, legend_size);
+            }
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for SunburstChart {
+    fn draw(&self) {
+        build_window(ChartType::Sunburst(self.clone()));
+    }
+}
+
+// Position of a node in the sunburst, angles are proportions of a full turn.
+struct Segment {
+    depth: usize,
+    root: usize,
+    start_rad: f64,
+    end_rad: f64,
+}
+
+// Calculates the ring and angular span of each node, where each child fills its share of its parent's span.
+fn calc_segments(parents: &Vec<Option<usize>>, data: &Vec<f64>) -> Vec<Segment> {
+    let num_nodes = data.len();
+    let children: Vec<Vec<usize>> = (0..num_nodes)
+        .map(|n| (0..num_nodes).filter(|&c| parents.get(c).cloned().unwrap_or(None) == Some(n)).collect())
+        .collect();
+
+    // Total of each node, children totals are found before their parents
+    fn calc_total(n: usize, data: &Vec<f64>, children: &Vec<Vec<usize>>, totals: &mut Vec<Option<f64>>, depth: usize) -> f64 {
+        if let Some(total) = totals[n] {
+            return total;
+        }
+        // depth is limited by the number of nodes to stop cycles repeating forever
+        let mut children_total = 0.0;
+        if depth < data.len() {
+            for &c in children[n].iter() {
+                children_total += calc_total(c, data, children, totals, depth + 1);
+            }
+        }
+        let total = data[n].max(0.0).max(children_total);
+        totals[n] = Some(total);
+        total
+    }
+    let mut totals: Vec<Option<f64>> = vec![None; num_nodes];
+    for n in 0..num_nodes {
+        calc_total(n, data, &children, &mut totals, 0);
+    }
+    let totals: Vec<f64> = totals.iter().map(|t| t.unwrap_or(0.0)).collect();
+
+    let mut segments: Vec<Segment> = (0..num_nodes).map(|n| Segment { depth: 0, root: n, start_rad: 0.0, end_rad: 0.0 }).collect();
+
+    // Breadth first from the roots, splitting the span of each node between its children
+    let roots: Vec<usize> = (0..num_nodes).filter(|&n| parents.get(n).cloned().unwrap_or(None).is_none()).collect();
+    let roots_total = roots.iter().fold(0.0, |acc, &n| acc + totals[n]);
+    let mut queue: Vec<usize> = Vec::new();
+    let mut start = 0.0;
+    for (i, &n) in roots.iter().enumerate() {
+        let span = if roots_total > 0.0 { totals[n] / roots_total } else { 0.0 };
+        segments[n] = Segment { depth: 0, root: i, start_rad: start, end_rad: start + span };
+        start += span;
+        queue.push(n);
+    }
+    let mut visited: Vec<bool> = vec![false; num_nodes];
+    while queue.len() > 0 {
+        let n = queue.remove(0);
+        if visited[n] { continue; }
+        visited[n] = true;
+
+        let mut start = segments[n].start_rad;
+        let parent_span = segments[n].end_rad - segments[n].start_rad;
+        for &c in children[n].iter() {
+            let span = if totals[n] > 0.0 { totals[c] / totals[n] * parent_span } else { 0.0 };
+            segments[c] = Segment { depth: segments[n].depth + 1, root: segments[n].root, start_rad: start, end_rad: start + span };
+            start += span;
+            queue.push(c);
+        }
+    }
+    segments
+}
@@ -10,6 +10,9 @@ use chart_builder::charts::*;
 pub struct VerticalBarChart {
     data_labels: Vec<String>,
     data: Vec<Vec<f64>>,
+    y_lower_errors: Vec<Vec<f64>>,
+    y_upper_errors: Vec<Vec<f64>>,
+    error_cap_width: f64,
     pub chart_prop: ChartProp,
     pub axis_prop: AxisProp,
 }
@@ -37,10 +40,42 @@ impl VerticalBarChart {
         VerticalBarChart {
             data_labels: new_data_labels,
             data: new_data,
+            y_lower_errors: Vec::new(),
+            y_upper_errors: Vec::new(),
+            error_cap_width: 0.02,
             chart_prop: ChartProp::new(chart_title, &axis_type),
             axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
         }
     }
+    /// Sets symmetric errors on the y-axis, shown as an error bar on each bar (no error bars by default).
+    ///
+    /// ```new_errors``` is the number data for the size of the error either side of each bar,
+    /// with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors(&mut self, new_errors: Vec<Vec<f64>>) {
+        self.set_y_errors_asymmetric(new_errors.clone(), new_errors);
+    }
+    /// Sets asymmetric errors on the y-axis, shown as an error bar on each bar (no error bars by default).
+    ///
+    /// ```new_lower_errors``` and ```new_upper_errors``` are the number data for the size of the error
+    /// below and above each bar, with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors_asymmetric(&mut self, new_lower_errors: Vec<Vec<f64>>, new_upper_errors: Vec<Vec<f64>>) {
+        self.y_lower_errors = new_lower_errors;
+        self.y_upper_errors = new_upper_errors;
+
+        let y_axis_props = calc_error_axis_props(&self.data, &self.y_lower_errors, &self.y_upper_errors, true, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    /// Sets the width of the caps at the ends of error bars (0.02 by default).
+    ///
+    /// ```cap_width``` is a f64 number specifying the width as a proportion of the width of the window, 0.0 draws no caps.
+    pub fn set_error_cap_width(&mut self, cap_width: f64) {
+        self.error_cap_width = cap_width;
+    }
     pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
         let data_labels = self.data_labels.clone();
         let data_y = self.data.clone();
@@ -48,6 +83,10 @@ impl VerticalBarChart {
 
         let chart_title = self.chart_prop.chart_title.clone();
 
+        let y_lower_errors = self.y_lower_errors.clone();
+        let y_upper_errors = self.y_upper_errors.clone();
+        let error_cap_width = self.error_cap_width;
+
         let x_axis_title = self.axis_prop.x_axis_title.clone();
         let x_axis_scale = self.axis_prop.x_axis_scale;
 
@@ -116,6 +155,25 @@ impl VerticalBarChart {
                 disp += 1.0;
             }
 
+            // Draw error bars over the centre of each bar
+            let mut disp = -0.5 * (data_y.len() as f64);
+            cr.set_source_rgb(0.0, 0.0, 0.0);
+            for j in 0..data_y.len() {
+                for i in 0..data_labels.len() {
+                    let lower_error = get_error(&y_lower_errors, j, i);
+                    let upper_error = get_error(&y_upper_errors, j, i);
+                    if lower_error == 0.0 && upper_error == 0.0 {
+                        continue;
+                    }
+                    let x = _left_bound - (x_delimiter_interval / 2.0) + x_delimiter_interval * ((i + 1) as f64) + (bar_width * (disp + 0.5)) * _horizontal_scaling;
+                    draw_error_bar(cr, x,
+                        _lower_bound - (get_percentage_in_bounds(data_y[j][i] - lower_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                        _lower_bound - (get_percentage_in_bounds(data_y[j][i] + upper_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                        true, error_cap_width, h_scale, v_scale);
+                }
+                disp += 1.0;
+            }
+
             // Chart Title
             draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
 
@@ -123,7 +181,7 @@ impl VerticalBarChart {
             draw_x_axis_cat(cr, scalings,
                 &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
                 screen_size,
-                false);
+                false, 1);
             draw_y_axis_con(cr, scalings,
                 y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
                 screen_size);
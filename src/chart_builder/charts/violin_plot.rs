@@ -0,0 +1,284 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Violin Plot.
+///
+/// This chart is used for statistical analysis of data.
+/// Shows the distribution of a set of data as a mirrored density curve, revealing multiple peaks hidden by a box plot.
+#[derive(Clone)]
+pub struct ViolinPlot {
+    data_labels: Vec<String>,
+    data: Vec<Vec<f64>>,
+    bandwidth: Option<f64>,
+    inner_box: bool,
+    quartile_lines: bool,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl ViolinPlot {
+    /// Creates a new instance of a ViolinPlot.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` contains strings placed under each violin naming them.
+    ///
+    /// ```new_data``` is the number data for which the density is estimated to create a violin.
+    /// Each inner vector represents a new violin named by the same index element in new_data_labels,
+    /// no violin is drawn for an empty vector but its label is still shown.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data: Vec<Vec<f64>>) -> ViolinPlot {
+        let x_axis_bounds = (0.0, 0.0);
+        let x_axis_scale = 1.0 / (new_data_labels.len() as f64);
+        let non_empty_data: Vec<Vec<f64>> = new_data.iter().filter(|group| group.len() > 0).cloned().collect();
+        let y_axis_props = calc_axis_props(&non_empty_data, true, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        ViolinPlot {
+            data_labels: new_data_labels,
+            data: new_data,
+            bandwidth: None,
+            inner_box: true,
+            quartile_lines: false,
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets the bandwidth (smoothing) used in estimating the density of every violin.
+    ///
+    /// ```new_bandwidth``` is a positive f64 number, larger values give smoother violins.
+    ///
+    /// By default a bandwidth is calculated for each violin from its data using Silverman's rule of thumb,
+    /// bandwidths that are not finite and greater than zero are ignored.
+    pub fn set_bandwidth(&mut self, new_bandwidth: f64) {
+        if !(new_bandwidth.is_finite() && new_bandwidth > 0.0) {
+            return;
+        }
+        self.bandwidth = Some(new_bandwidth);
+    }
+    /// Set if a small box plot should be drawn inside each violin (true by default).
+    ///
+    /// ```inner_box``` is a boolean value that should be set to true to show the inner box plot.
+    pub fn set_inner_box(&mut self, inner_box: bool) {
+        self.inner_box = inner_box;
+    }
+    /// Set if lines should be drawn across each violin at its quartiles (false by default).
+    ///
+    /// ```quartile_lines``` is a boolean value that should be set to true to show the quartile lines.
+    pub fn set_quartile_lines(&mut self, quartile_lines: bool) {
+        self.quartile_lines = quartile_lines;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let data = self.data.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let inner_box = self.inner_box;
+        let quartile_lines = self.quartile_lines;
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        let screen_size = self.chart_prop.screen_size;
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        scalings = get_normal_scale();
+
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // calculate quartiles and density curves for drawing violins
+        let mut lq: Vec<f64> = Vec::new();
+        let mut med: Vec<f64> = Vec::new();
+        let mut uq: Vec<f64> = Vec::new();
+        let mut min: Vec<f64> = Vec::new();
+        let mut max: Vec<f64> = Vec::new();
+        // (value, density) pairs from min to max of each set of data
+        let mut densities: Vec<Vec<(f64, f64)>> = Vec::new();
+        let num_points = 100;
+
+        for i in 0..data.len() {
+            // Sort data for determing percentile
+            let mut sorted_data =  data[i].clone();
+            sorted_data.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+            // empty groups have no statistics or density and are not drawn
+            if sorted_data.len() == 0 {
+                lq.push(0./0.);
+                med.push(0./0.);
+                uq.push(0./0.);
+                min.push(0./0.);
+                max.push(0./0.);
+                densities.push(Vec::new());
+                continue;
+            }
+
+            // Lower quartile is the (n + 1) ÷ 4 th value.
+            lq.push(percentile(&sorted_data, 0.25));
+            // Median is the (n + 1) ÷ 2 th value.
+            med.push(percentile(&sorted_data, 0.5));
+            // Upper quartile is the 3 (n + 1) ÷ 4 th value.
+            uq.push(percentile(&sorted_data, 0.75));
+
+            min.push(sorted_data[0]);
+            max.push(sorted_data[sorted_data.len() - 1]);
+
+            let bandwidth = match self.bandwidth {
+                Some(b) => b,
+                None => calc_bandwidth(&sorted_data),
+            };
+
+            // density is only drawn within the range of the data
+            let mut density: Vec<(f64, f64)> = Vec::new();
+            for j in 0..(num_points + 1) {
+                let val = min[i] + (max[i] - min[i]) * (j as f64) / (num_points as f64);
+                density.push((val, calc_density(&sorted_data, bandwidth, val)));
+            }
+            densities.push(density);
+        }
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing violin plot components
+
+            let radius_scaling;
+            if screen_size.1 > screen_size.0 {
+                radius_scaling = _horizontal_scaling.min(_vertical_scaling);
+            } else {
+                radius_scaling = _horizontal_scaling.max(_vertical_scaling);
+            }
+            let mark_radius = 0.006 * radius_scaling;
+
+            use std::f64::consts::PI;
+
+            let x_delimiter_interval: f64 = _horizontal_scaling * x_axis_scale;
+            // widest point of each violin fills 80% of the space for its label
+            let max_half_width = x_delimiter_interval * 0.4;
+            let box_half_width = (max_half_width * 0.12).min(0.012 * h_scale);
+
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            for i in 0..data.len() {
+                if densities[i].len() == 0 { continue; }
+                let x = _left_bound - (x_delimiter_interval / 2.0) + x_delimiter_interval * ((i + 1) as f64);
+
+                // each violin is scaled to its own widest point
+                let max_density = densities[i].iter().fold(0.0, |cur_max: f64, &(_, d)| cur_max.max(d));
+                let get_half_width = |density: f64| if max_density > 0.0 { density / max_density * max_half_width } else { 0.0 };
+
+                // right side from min up to max, then left side back down
+                cr.move_to(x + get_half_width(densities[i][0].1), get_y(densities[i][0].0));
+                for j in 1..densities[i].len() {
+                    cr.line_to(x + get_half_width(densities[i][j].1), get_y(densities[i][j].0));
+                }
+                for j in (0..densities[i].len()).rev() {
+                    cr.line_to(x - get_half_width(densities[i][j].1), get_y(densities[i][j].0));
+                }
+                cr.close_path();
+                set_nth_colour_opacity(cr, i, 0.7);
+                cr.fill_preserve();
+                set_nth_colour(cr, i);
+                cr.set_line_width(0.002);
+                cr.stroke();
+
+                // lines across violin at each quartile, dashed for lower and upper quartile
+                if quartile_lines == true {
+                    cr.save();
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                    let quartiles = [lq[i], med[i], uq[i]];
+                    for q in 0..quartiles.len() {
+                        if q == 1 {
+                            cr.set_dash(&[], 0.0);
+                        } else {
+                            cr.set_dash(&[0.01, 0.006], 0.0);
+                        }
+                        // find width of violin at quartile from the nearest point of the density curve
+                        let index = (get_percentage_in_bounds(quartiles[q], min[i], max[i]) * (num_points as f64)).round();
+                        let index = if index.is_finite() { (index as usize).min(num_points) } else { 0 };
+                        let half_width = get_half_width(densities[i][index].1);
+                        cr.move_to(x - half_width, get_y(quartiles[q]));
+                        cr.line_to(x + half_width, get_y(quartiles[q]));
+                        cr.stroke();
+                    }
+                    cr.restore();
+                }
+
+                // small box plot inside violin
+                if inner_box == true {
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+
+                    // line from min to max
+                    cr.set_line_width(0.002 * h_scale);
+                    cr.move_to(x, get_y(min[i]));
+                    cr.line_to(x, get_y(max[i]));
+                    cr.stroke();
+
+                    // lq to uq block
+                    cr.rectangle(x - box_half_width, get_y(uq[i]), box_half_width * 2.0, get_y(lq[i]) - get_y(uq[i]));
+                    cr.fill();
+
+                    // median mark
+                    cr.save();
+                    cr.translate(x, get_y(med[i]));
+                    cr.scale(h_scale, v_scale);
+                    cr.arc(0.0, 0.0, mark_radius, 0.0, 2.0 * PI);
+                    cr.set_source_rgb(1.0, 1.0, 1.0);
+                    cr.fill();
+                    cr.restore();
+                }
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_cat(cr, scalings,
+                &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size,
+                false, 1);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
+                screen_size);
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for ViolinPlot {
+    fn draw(&self) {
+        build_window(ChartType::Violin(self.clone()));
+    }
+}
@@ -0,0 +1,186 @@
+//!
+
+
+use chart_builder::charts::*;
+
+/// Structure used for storing chart related data and the drawing of a Waterfall Chart.
+///
+/// Shows how a starting value is changed by a series of increases and decreases, e.g. a budget bridge.
+#[derive(Clone)]
+pub struct WaterfallChart {
+    data_labels: Vec<String>,
+    data: Vec<f64>,
+    totals: Vec<usize>,
+    pub chart_prop: ChartProp,
+    pub axis_prop: AxisProp,
+}
+
+impl WaterfallChart {
+    /// Creates a new instance of a WaterfallChart.
+    ///
+    /// ```chart_title``` is the String to specify the name of the chart displayed at the top of the window.
+    ///
+    /// ```new_data_labels``` is the string data placed on the x-axis of the chart, each for a bar.
+    ///
+    /// ```new_data``` is the number data specifying the change (increase or decrease) shown by each bar,
+    /// with indexes corresponding to the same index in new_data_labels.
+    pub fn new(chart_title: String, new_data_labels: Vec<String>, new_data: Vec<f64>) -> WaterfallChart {
+        let x_axis_bounds = (0.0, 0.0);
+        let x_axis_scale = 1.0 / (new_data_labels.len() as f64);
+
+        let running_totals = calc_running_totals(&new_data, &Vec::new());
+        let y_axis_props = calc_axis_props(&vec![running_totals, vec![0.0]], true, false);
+        let y_axis_bounds = y_axis_props.0;
+        let y_axis_scale = y_axis_props.1;
+
+        let axis_type: AxisType =
+            if y_axis_bounds.0 < 0.0 && y_axis_bounds.1 > 0.0 { AxisType::DoubleVertical }
+            else { AxisType::Single };
+
+        WaterfallChart {
+            data_labels: new_data_labels,
+            data: new_data,
+            totals: Vec::new(),
+            chart_prop: ChartProp::new(chart_title, &axis_type),
+            axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
+        }
+    }
+    /// Sets which bars show a (sub)total rather than a change.
+    ///
+    /// ```new_totals``` contains the indexes of the bars drawn from zero to the running total of all bars before them.
+    /// The values in the data at these indexes are ignored.
+    pub fn set_totals(&mut self, new_totals: Vec<usize>) {
+        self.totals = new_totals;
+
+        // ignored values can change the range of the running total
+        let running_totals = calc_running_totals(&self.data, &self.totals);
+        let y_axis_props = calc_axis_props(&vec![running_totals, vec![0.0]], true, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
+        let data_labels = self.data_labels.clone();
+        let totals = self.totals.clone();
+
+        let chart_title = self.chart_prop.chart_title.clone();
+
+        let x_axis_title = self.axis_prop.x_axis_title.clone();
+        let x_axis_scale = self.axis_prop.x_axis_scale;
+
+        let y_axis_title = self.axis_prop.y_axis_title.clone();
+        let y_axis_scale = self.axis_prop.y_axis_scale;
+        let y_axis_bounds: (f64, f64) = self.axis_prop.y_axis_bounds;
+        let y_axis_min = y_axis_bounds.0;
+        let y_axis_max = y_axis_bounds.1;
+
+        let screen_size = self.chart_prop.screen_size;
+
+        let mut h_scale = screen_size.1 / screen_size.0;
+        let mut v_scale = screen_size.0 / screen_size.1;
+
+        // Always make text and objects smaller rather than bigger as guarnteed to fit on screen
+        if h_scale < v_scale {
+            v_scale = 1.0;
+        } else {
+            h_scale = 1.0;
+        }
+
+        let scalings: (f64, f64, f64, f64 ,f64, f64);
+        scalings = get_normal_scale();
+
+        let _horizontal_scaling = scalings.0;
+        let _vertical_scaling = scalings.1;
+        let _left_bound = scalings.2;
+        let _right_bound = scalings.3;
+        let _lower_bound = scalings.4;
+        let _upper_bound = scalings.5;
+
+        // running total after each bar, each bar floats from the previous running total to its own
+        let running_totals = calc_running_totals(&self.data, &totals);
+
+        drawing_area.connect_draw(move |_, cr| {
+            cr.set_dash(&[3., 2., 1.], 1.);
+            assert_eq!(cr.get_dash(), (vec![3., 2., 1.], 1.));
+
+            set_defaults(cr, screen_size);
+
+            // Drawing Waterfall chart components
+            let x_delimiter_interval: f64 = _horizontal_scaling * x_axis_scale;
+            let bar_width = x_delimiter_interval * 0.6;
+            let get_y = |y_val: f64| _lower_bound - (get_percentage_in_bounds(y_val, y_axis_min, y_axis_max) * _vertical_scaling);
+
+            let mut prev_total = 0.0;
+            for i in 0..data_labels.len() {
+                let x = _left_bound - (x_delimiter_interval / 2.0) + x_delimiter_interval * ((i + 1) as f64);
+
+                // totals are blue, increases green and decreases red
+                let bar_start: f64;
+                if totals.contains(&i) {
+                    bar_start = 0.0;
+                    set_nth_colour(cr, 3);
+                } else if running_totals[i] >= prev_total {
+                    bar_start = prev_total;
+                    set_nth_colour(cr, 1);
+                } else {
+                    bar_start = prev_total;
+                    set_nth_colour(cr, 0);
+                }
+
+                cr.rectangle(
+                    x - bar_width / 2.0,
+                    get_y(bar_start),
+                    bar_width,
+                    get_y(running_totals[i]) - get_y(bar_start));
+                cr.fill();
+
+                // connector from the top of this bar to the start of the next
+                if i + 1 < data_labels.len() {
+                    cr.save();
+                    cr.set_source_rgb(0.0, 0.0, 0.0);
+                    cr.set_line_width(0.0015 * v_scale);
+                    cr.set_dash(&[0.006, 0.004], 0.0);
+                    cr.move_to(x + bar_width / 2.0, get_y(running_totals[i]));
+                    cr.line_to(x + x_delimiter_interval - bar_width / 2.0, get_y(running_totals[i]));
+                    cr.stroke();
+                    cr.restore();
+                }
+
+                prev_total = running_totals[i];
+            }
+
+            // Chart Title
+            draw_title(cr, _left_bound, _upper_bound, h_scale, v_scale, &chart_title);
+
+            // Draw Axis
+            draw_x_axis_cat(cr, scalings,
+                &data_labels, x_axis_scale, calc_zero_intercept(y_axis_min, y_axis_max), &x_axis_title,
+                screen_size,
+                false, 1);
+            draw_y_axis_con(cr, scalings,
+                y_axis_min, y_axis_max, y_axis_scale, 0.0, &y_axis_title,
+                screen_size);
+
+            Inhibit(false)
+        });
+    }
+    pub(in chart_builder) fn get_chart_prop(&self) -> ChartProp { self.chart_prop.clone() }
+}
+
+impl Chart for WaterfallChart {
+    fn draw(&self) {
+        build_window(ChartType::Waterfall(self.clone()));
+    }
+}
+
+// Running total after each change, where bars at the indexes in totals do not change the total.
+fn calc_running_totals(data: &Vec<f64>, totals: &Vec<usize>) -> Vec<f64> {
+    let mut running_totals: Vec<f64> = Vec::new();
+    let mut total = 0.0;
+    for i in 0..data.len() {
+        if !totals.contains(&i) {
+            total += data[i];
+        }
+        running_totals.push(total);
+    }
+    running_totals
+}
@@ -11,6 +11,11 @@ pub struct XYScatterPlot {
     data_x: Vec<Vec<f64>>,
     data_y: Vec<Vec<f64>>,
     best_fit_line: bool,
+    x_lower_errors: Vec<Vec<f64>>,
+    x_upper_errors: Vec<Vec<f64>>,
+    y_lower_errors: Vec<Vec<f64>>,
+    y_upper_errors: Vec<Vec<f64>>,
+    error_cap_width: f64,
     pub chart_prop: ChartProp,
     pub axis_prop: AxisProp,
 }
@@ -42,6 +47,11 @@ impl XYScatterPlot {
             data_x: new_data_x,
             data_y: new_data_y,
             best_fit_line: false,
+            x_lower_errors: Vec::new(),
+            x_upper_errors: Vec::new(),
+            y_lower_errors: Vec::new(),
+            y_upper_errors: Vec::new(),
+            error_cap_width: 0.02,
             chart_prop: ChartProp::new(chart_title, &axis_type),
             axis_prop: AxisProp::new(x_axis_bounds, y_axis_bounds, x_axis_scale, y_axis_scale),
         }
@@ -52,6 +62,58 @@ impl XYScatterPlot {
     pub fn set_best_fit_line(&mut self, best_fit_line: bool) {
         self.best_fit_line = best_fit_line;
     }
+    /// Sets symmetric errors on the x-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_errors``` is the number data for the size of the error either side of each point,
+    /// with indexes corresponding to the same indexes in the data.
+    ///
+    /// The x-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_x_errors(&mut self, new_errors: Vec<Vec<f64>>) {
+        self.set_x_errors_asymmetric(new_errors.clone(), new_errors);
+    }
+    /// Sets asymmetric errors on the x-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_lower_errors``` and ```new_upper_errors``` are the number data for the size of the error
+    /// left and right of each point, with indexes corresponding to the same indexes in the data.
+    ///
+    /// The x-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_x_errors_asymmetric(&mut self, new_lower_errors: Vec<Vec<f64>>, new_upper_errors: Vec<Vec<f64>>) {
+        self.x_lower_errors = new_lower_errors;
+        self.x_upper_errors = new_upper_errors;
+
+        let x_axis_props = calc_error_axis_props(&self.data_x, &self.x_lower_errors, &self.x_upper_errors, false, true);
+        self.axis_prop.x_axis_bounds = x_axis_props.0;
+        self.axis_prop.x_axis_scale = x_axis_props.1;
+    }
+    /// Sets symmetric errors on the y-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_errors``` is the number data for the size of the error either side of each point,
+    /// with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors(&mut self, new_errors: Vec<Vec<f64>>) {
+        self.set_y_errors_asymmetric(new_errors.clone(), new_errors);
+    }
+    /// Sets asymmetric errors on the y-axis, shown as an error bar on each point (no error bars by default).
+    ///
+    /// ```new_lower_errors``` and ```new_upper_errors``` are the number data for the size of the error
+    /// below and above each point, with indexes corresponding to the same indexes in the data.
+    ///
+    /// The y-axis bounds are recalculated so that error bars are never clipped.
+    pub fn set_y_errors_asymmetric(&mut self, new_lower_errors: Vec<Vec<f64>>, new_upper_errors: Vec<Vec<f64>>) {
+        self.y_lower_errors = new_lower_errors;
+        self.y_upper_errors = new_upper_errors;
+
+        let y_axis_props = calc_error_axis_props(&self.data_y, &self.y_lower_errors, &self.y_upper_errors, false, false);
+        self.axis_prop.y_axis_bounds = y_axis_props.0;
+        self.axis_prop.y_axis_scale = y_axis_props.1;
+    }
+    /// Sets the width of the caps at the ends of error bars (0.02 by default).
+    ///
+    /// ```cap_width``` is a f64 number specifying the width as a proportion of the width of the window, 0.0 draws no caps.
+    pub fn set_error_cap_width(&mut self, cap_width: f64) {
+        self.error_cap_width = cap_width;
+    }
     pub(in chart_builder) fn draw_chart(&self, drawing_area: &DrawingArea) {
         let data_x = self.data_x.clone();
         let data_y = self.data_y.clone();
@@ -59,6 +121,12 @@ impl XYScatterPlot {
 
         let chart_title = self.chart_prop.chart_title.clone();
 
+        let x_lower_errors = self.x_lower_errors.clone();
+        let x_upper_errors = self.x_upper_errors.clone();
+        let y_lower_errors = self.y_lower_errors.clone();
+        let y_upper_errors = self.y_upper_errors.clone();
+        let error_cap_width = self.error_cap_width;
+
         let best_fit_line = self.best_fit_line;
 
         let x_axis_title = self.axis_prop.x_axis_title.clone();
@@ -167,6 +235,24 @@ impl XYScatterPlot {
                     cr.fill();
                     cr.stroke();
                     cr.restore();
+
+                    // draw error bars through (x,y)
+                    let lower_error = get_error(&x_lower_errors, j, i);
+                    let upper_error = get_error(&x_upper_errors, j, i);
+                    if lower_error != 0.0 || upper_error != 0.0 {
+                        draw_error_bar(cr, y,
+                            _left_bound + (get_percentage_in_bounds(x_val - lower_error, x_axis_min, x_axis_max) * _horizontal_scaling),
+                            _left_bound + (get_percentage_in_bounds(x_val + upper_error, x_axis_min, x_axis_max) * _horizontal_scaling),
+                            false, error_cap_width, h_scale, v_scale);
+                    }
+                    let lower_error = get_error(&y_lower_errors, j, i);
+                    let upper_error = get_error(&y_upper_errors, j, i);
+                    if lower_error != 0.0 || upper_error != 0.0 {
+                        draw_error_bar(cr, x,
+                            _lower_bound - (get_percentage_in_bounds(y_val - lower_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                            _lower_bound - (get_percentage_in_bounds(y_val + upper_error, y_axis_min, y_axis_max) * _vertical_scaling),
+                            true, error_cap_width, h_scale, v_scale);
+                    }
                 }
             }
 
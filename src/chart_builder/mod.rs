@@ -102,8 +102,38 @@ pub use self::charts::radar_chart::RadarChart;
 pub use self::charts::area_chart::AreaChart;
 pub use self::charts::stacked_area_chart::StackedAreaChart;
 pub use self::charts::line_chart::LineChart;
+pub use self::charts::line_chart::StepMode;
 pub use self::charts::xy_scatter_plot::XYScatterPlot;
 pub use self::charts::bubble_chart::BubbleChart;
+pub use self::charts::contour_plot::ContourPlot;
+pub use self::charts::violin_plot::ViolinPlot;
+pub use self::charts::candlestick_chart::CandlestickChart;
+pub use self::charts::gantt_chart::GanttChart;
+pub use self::charts::waterfall_chart::WaterfallChart;
+pub use self::charts::funnel_chart::FunnelChart;
+pub use self::charts::polar_area_chart::PolarAreaChart;
+pub use self::charts::polar_plot::PolarPlot;
+pub use self::charts::stem_plot::StemPlot;
+pub use self::charts::lollipop_chart::LollipopChart;
+pub use self::charts::sankey_chart::SankeyChart;
+pub use self::charts::sunburst_chart::SunburstChart;
+pub use self::charts::parallel_coordinates::ParallelCoordinates;
+pub use self::charts::scatter_matrix::ScatterMatrix;
+pub use self::charts::histogram_2d::Histogram2D;
+pub use self::charts::hexbin_plot::HexbinPlot;
+pub use self::charts::density_plot::DensityPlot;
+pub use self::charts::density_plot::Kernel;
+pub use self::charts::density_plot::Bandwidth;
+pub use self::charts::ecdf_plot::EcdfPlot;
+pub use self::charts::qq_plot::QQPlot;
+pub use self::charts::qq_plot::Distribution;
+pub use self::charts::gauge_chart::GaugeChart;
+pub use self::charts::bullet_chart::BulletChart;
+pub use self::charts::calendar_heatmap::CalendarHeatmap;
+pub use self::charts::dendrogram::Dendrogram;
+pub use self::charts::dendrogram::Linkage;
+pub use self::charts::quiver_plot::QuiverPlot;
+pub use self::charts::function_plot::FunctionPlot;
 
 /*
  * Helper functions
@@ -113,6 +143,171 @@ pub(in chart_builder) fn get_percentage_in_bounds(value: f64, min: f64, max: f64
     (value - min) / (max - min)
 }
 
+// Sample standard deviation of data.
+pub(in chart_builder) fn calc_standard_deviation(data: &Vec<f64>) -> f64 {
+    let n = data.len() as f64;
+    let mean = data.iter().fold(0.0, |acc, &x| acc + x) / n;
+    (data.iter().fold(0.0, |acc, &x| acc + (x - mean).powf(2.0)) / (n - 1.0)).sqrt()
+}
+
+// Silverman's rule of thumb for the bandwidth of a kernel density estimate.
+pub(in chart_builder) fn calc_bandwidth(data: &Vec<f64>) -> f64 {
+    let n = data.len() as f64;
+    let sd = calc_standard_deviation(data);
+
+    let mut sorted_data = data.clone();
+    sorted_data.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    use chart_builder::axis_prop::percentile;
+    let iqr = percentile(&sorted_data, 0.75) - percentile(&sorted_data, 0.25);
+
+    // iqr of 0 would give a bandwidth of 0, so fall back to using the standard deviation
+    let spread = if iqr > 0.0 { sd.min(iqr / 1.34) } else { sd };
+    let bandwidth = 0.9 * spread * n.powf(-0.2);
+
+    // a single value (or all equal values) has no spread
+    if bandwidth > 0.0 { bandwidth } else { 1.0 }
+}
+
+// Gaussian kernel density estimate of data at x.
+pub(in chart_builder) fn calc_density(data: &Vec<f64>, bandwidth: f64, x: f64) -> f64 {
+    use std::f64::consts::PI;
+    let sum = data.iter().fold(0.0, |acc, &val| {
+        let u = (x - val) / bandwidth;
+        acc + (-0.5 * u * u).exp() / (2.0 * PI).sqrt()
+    });
+    sum / ((data.len() as f64) * bandwidth)
+}
+
+// Scott's rule of thumb for the bandwidth of a kernel density estimate.
+pub(in chart_builder) fn calc_scott_bandwidth(data: &Vec<f64>) -> f64 {
+    let n = data.len() as f64;
+    let bandwidth = 1.06 * calc_standard_deviation(data) * n.powf(-0.2);
+
+    // a single value (or all equal values) has no spread
+    if bandwidth > 0.0 { bandwidth } else { 1.0 }
+}
+
+// Epanechnikov kernel density estimate of data at x.
+pub(in chart_builder) fn calc_epanechnikov_density(data: &Vec<f64>, bandwidth: f64, x: f64) -> f64 {
+    let sum = data.iter().fold(0.0, |acc, &val| {
+        let u = (x - val) / bandwidth;
+        if u.abs() <= 1.0 { acc + 0.75 * (1.0 - u * u) } else { acc }
+    });
+    sum / ((data.len() as f64) * bandwidth)
+}
+
+// Quantile of the standard normal distribution at proportion p, using Acklam's rational approximation.
+pub(in chart_builder) fn calc_normal_quantile(p: f64) -> f64 {
+    let a = [-3.969683028665376e+01, 2.209460984245205e+02, -2.759285104469687e+02,
+        1.383577518672690e+02, -3.066479806614716e+01, 2.506628277459239e+00];
+    let b = [-5.447609879822406e+01, 1.615858368580409e+02, -1.556989798598866e+02,
+        6.680131188771972e+01, -1.328068155288572e+01];
+    let c = [-7.784894002430293e-03, -3.223964580411365e-01, -2.400758277161838e+00,
+        -2.549732539343734e+00, 4.374664141464968e+00, 2.938163982698783e+00];
+    let d = [7.784695709041462e-03, 3.224671290700398e-01, 2.445134137142996e+00,
+        3.754408661907416e+00];
+
+    // separate approximations for the tails and the central region
+    let p_low = 0.02425;
+    if p <= 0.0 {
+        -1.0 / 0.0
+    } else if p >= 1.0 {
+        1.0 / 0.0
+    } else if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5]) /
+            ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    } else if p > 1.0 - p_low {
+        -calc_normal_quantile(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q /
+            (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
+    }
+}
+
+// Cumulative probability of the standard normal distribution at x, using a Chebyshev fitted complementary error function.
+pub(in chart_builder) fn calc_normal_cdf(x: f64) -> f64 {
+    let z = (x / 2f64.sqrt()).abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let erfc = t * (-z * z - 1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418 + t * (-0.18628806 +
+        t * (0.27886807 + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))))).exp();
+    if x >= 0.0 { 1.0 - 0.5 * erfc } else { 0.5 * erfc }
+}
+
+// Samples a curve of points (x, y) = function(t) for t from t_min to t_max, returning the lines to draw.
+// Intervals are halved where the curve bends (relative to the span of the curve) so curved parts get more points.
+// The curve is broken into separate lines at non-finite points and at jumps that remain after the smallest interval.
+pub(in chart_builder) fn sample_curve<F: Fn(f64) -> (f64, f64)>(function: &F, t_min: f64, t_max: f64) -> Vec<Vec<(f64, f64)>> {
+    let num_intervals = 64;
+    let max_depth = 10;
+
+    let is_finite = |point: (f64, f64)| point.0.is_finite() && point.1.is_finite();
+
+    // span of the curve from evenly spaced points, used to measure bends relative to the size of the chart
+    let initial: Vec<(f64, (f64, f64))> = (0..(num_intervals + 1)).map(|i| {
+        let t = t_min + (t_max - t_min) * (i as f64) / (num_intervals as f64);
+        (t, function(t))
+    }).collect();
+    let finite: Vec<(f64, f64)> = initial.iter().map(|&(_, point)| point).filter(|&point| is_finite(point)).collect();
+    let get_span = |values: Vec<f64>| {
+        let span = values.iter().fold(0./0., |cur_max: f64, &x| cur_max.max(x)) - values.iter().fold(0./0., |cur_min: f64, &x| cur_min.min(x));
+        if span > 0.0 { span } else { 1.0 }
+    };
+    let x_span = get_span(finite.iter().map(|point| point.0).collect());
+    let y_span = get_span(finite.iter().map(|point| point.1).collect());
+
+    // points in order, with None where the line is broken
+    let mut points: Vec<Option<(f64, f64)>> = Vec::new();
+    // (t, point) of the start and end of intervals still to be sampled, and their depth
+    let mut stack: Vec<((f64, (f64, f64)), (f64, (f64, f64)), usize)> = Vec::new();
+    for i in (0..num_intervals).rev() {
+        stack.push((initial[i], initial[i + 1], 0));
+    }
+    points.push(if is_finite(initial[0].1) { Some(initial[0].1) } else { None });
+
+    while let Some((start, end, depth)) = stack.pop() {
+        let mid_t = (start.0 + end.0) / 2.0;
+        let mid = function(mid_t);
+
+        // distance of the middle from a straight line between the ends
+        let finite_count = [start.1, mid, end.1].iter().filter(|&&point| is_finite(point)).count();
+        let bend = if finite_count == 3 {
+            ((mid.0 - (start.1).0 / 2.0 - (end.1).0 / 2.0) / x_span).abs().max(((mid.1 - (start.1).1 / 2.0 - (end.1).1 / 2.0) / y_span).abs())
+        } else {
+            0.0
+        };
+
+        if depth < max_depth && (bend > 0.001 || (finite_count > 0 && finite_count < 3)) {
+            // the second half is pushed first so the first half is sampled first
+            stack.push(((mid_t, mid), end, depth + 1));
+            stack.push((start, (mid_t, mid), depth + 1));
+            continue;
+        }
+
+        if is_finite(end.1) && is_finite(start.1) {
+            // a jump that remains at the smallest interval is a discontinuity
+            let jump = (((end.1).1 - (start.1).1) / y_span).abs();
+            if depth >= max_depth && bend > 0.001 && jump > 0.05 {
+                points.push(None);
+            }
+        }
+        points.push(if is_finite(end.1) { Some(end.1) } else { None });
+    }
+
+    // split into lines at each break, single points are not drawn
+    let mut lines: Vec<Vec<(f64, f64)>> = vec![Vec::new()];
+    for point in points {
+        match point {
+            Some(point) => lines.last_mut().unwrap().push(point),
+            None => if lines.last().unwrap().len() > 0 { lines.push(Vec::new()); },
+        }
+    }
+    lines.retain(|line| line.len() > 1);
+    lines
+}
+
 /*
  * Public helper functions
  */
@@ -144,6 +339,74 @@ pub fn remove_outliers(data: &Vec<f64>) -> Vec<f64> {
     sorted_data
 }
 
+/// Clusters observations by repeatedly merging the two closest clusters, returning each merge in order.
+///
+/// ```data``` is a ```Vec<Vec<f64>>``` where each inner vector is an observation, distances between observations are Euclidean.
+///
+/// ```linkage``` specifies how the distance between two clusters is measured from the distances between their observations.
+///
+/// Each merge is ```(first cluster, second cluster, distance)```, where clusters are numbered from 0 for each observation
+/// and then n, n + 1, ... for the cluster created by each merge (n is the number of observations).
+pub fn hierarchical_clustering(data: &Vec<Vec<f64>>, linkage: Linkage) -> Vec<(usize, usize, f64)> {
+    let n = data.len();
+
+    // (cluster number, number of observations) of each cluster not yet merged,
+    // with the distances between them at the same indexes
+    let mut clusters: Vec<(usize, usize)> = (0..n).map(|i| (i, 1)).collect();
+    let mut distances: Vec<Vec<f64>> = Vec::new();
+    for i in 0..n {
+        distances.push((0..n).map(|j| {
+            data[i].iter().zip(data[j].iter()).fold(0.0, |acc, (&a, &b)| acc + (a - b).powf(2.0)).sqrt()
+        }).collect());
+    }
+
+    let mut merges: Vec<(usize, usize, f64)> = Vec::new();
+    while clusters.len() > 1 {
+        // closest pair of clusters
+        let mut closest = (0, 1, distances[0][1]);
+        for i in 0..clusters.len() {
+            for j in (i + 1)..clusters.len() {
+                if distances[i][j] < closest.2 {
+                    closest = (i, j, distances[i][j]);
+                }
+            }
+        }
+        let (i, j, distance) = closest;
+        let (size_i, size_j) = (clusters[i].1, clusters[j].1);
+        merges.push((clusters[i].0.min(clusters[j].0), clusters[i].0.max(clusters[j].0), distance));
+
+        // distance from the merged cluster to every other cluster
+        let mut new_distances: Vec<f64> = Vec::new();
+        for k in 0..clusters.len() {
+            if k == i || k == j {
+                continue;
+            }
+            new_distances.push(match linkage {
+                Linkage::Single => distances[i][k].min(distances[j][k]),
+                Linkage::Complete => distances[i][k].max(distances[j][k]),
+                Linkage::Average => (distances[i][k] * (size_i as f64) + distances[j][k] * (size_j as f64)) / ((size_i + size_j) as f64),
+            });
+        }
+
+        // remove both clusters (j is after i) and add the merged cluster at the end
+        for &index in [j, i].iter() {
+            clusters.remove(index);
+            distances.remove(index);
+            for row in distances.iter_mut() {
+                row.remove(index);
+            }
+        }
+        for k in 0..distances.len() {
+            distances[k].push(new_distances[k]);
+        }
+        new_distances.push(0.0);
+        distances.push(new_distances);
+        clusters.push((n + merges.len() - 1, size_i + size_j));
+    }
+
+    merges
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -160,6 +423,117 @@ mod tests {
         assert_eq!(get_percentage_in_bounds(12.0, 0.0, 10.0), 1.2);
     }
     #[test]
+    fn calc_bandwidth_tests() {
+        // Silverman's rule - 0.9 * min(sd, iqr / 1.34) * n^(-1/5)
+        let vec_1: Vec<f64> = vec![1.0,2.0,3.0,4.0,5.0];
+        let bandwidth_1 = 0.9 * (2.0 / 1.34) * (5.0 as f64).powf(-0.2);
+        assert!((calc_bandwidth(&vec_1) - bandwidth_1).abs() < 1e-12);
+
+        // No spread
+        let vec_2: Vec<f64> = vec![3.0,3.0,3.0];
+        assert_eq!(calc_bandwidth(&vec_2), 1.0);
+    }
+    #[test]
+    fn calc_density_tests() {
+        use std::f64::consts::PI;
+        // Single value gives a normal distribution centred on it
+        let vec_1: Vec<f64> = vec![0.0];
+        assert!((calc_density(&vec_1, 1.0, 0.0) - 1.0 / (2.0 * PI).sqrt()).abs() < 1e-12);
+        assert!((calc_density(&vec_1, 2.0, 2.0) - (-0.5 as f64).exp() / (2.0 * (2.0 * PI).sqrt())).abs() < 1e-12);
+
+        // Density integrates to 1
+        let vec_2: Vec<f64> = vec![1.0,2.0,2.5,7.0];
+        let integral = (0..3000).fold(0.0, |acc, i| acc + calc_density(&vec_2, 0.8, -10.0 + 0.01 * (i as f64)) * 0.01);
+        assert!((integral - 1.0).abs() < 1e-6);
+    }
+    #[test]
+    fn calc_scott_bandwidth_tests() {
+        // Scott's rule - 1.06 * sd * n^(-1/5)
+        let vec_1: Vec<f64> = vec![1.0,2.0,3.0,4.0,5.0];
+        let bandwidth_1 = 1.06 * (2.5 as f64).sqrt() * (5.0 as f64).powf(-0.2);
+        assert!((calc_scott_bandwidth(&vec_1) - bandwidth_1).abs() < 1e-12);
+
+        // No spread
+        let vec_2: Vec<f64> = vec![3.0,3.0,3.0];
+        assert_eq!(calc_scott_bandwidth(&vec_2), 1.0);
+    }
+    #[test]
+    fn calc_epanechnikov_density_tests() {
+        // Single value gives a parabola centred on it, zero outside of the bandwidth
+        let vec_1: Vec<f64> = vec![0.0];
+        assert_eq!(calc_epanechnikov_density(&vec_1, 1.0, 0.0), 0.75);
+        assert_eq!(calc_epanechnikov_density(&vec_1, 2.0, 1.0), 0.28125);
+        assert_eq!(calc_epanechnikov_density(&vec_1, 1.0, 1.5), 0.0);
+
+        // Density integrates to 1
+        let vec_2: Vec<f64> = vec![1.0,2.0,2.5,7.0];
+        let integral = (0..3000).fold(0.0, |acc, i| acc + calc_epanechnikov_density(&vec_2, 0.8, -10.005 + 0.01 * (i as f64)) * 0.01);
+        assert!((integral - 1.0).abs() < 1e-4);
+    }
+    #[test]
+    fn calc_normal_quantile_tests() {
+        assert_eq!(calc_normal_quantile(0.5), 0.0);
+        assert!((calc_normal_quantile(0.975) - 1.959963984540054).abs() < 1e-8);
+        assert!((calc_normal_quantile(0.841344746068543) - 1.0).abs() < 1e-8);
+
+        // Tails are symmetric
+        assert!((calc_normal_quantile(0.001) + 3.090232306167814).abs() < 1e-8);
+        assert_eq!(calc_normal_quantile(0.999), -calc_normal_quantile(0.001));
+    }
+    #[test]
+    fn calc_normal_cdf_tests() {
+        assert!((calc_normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((calc_normal_cdf(1.959963984540054) - 0.975).abs() < 1e-7);
+        assert!((calc_normal_cdf(-1.0) - 0.158655253931457).abs() < 1e-7);
+
+        // Inverse of the quantile function
+        assert!((calc_normal_cdf(calc_normal_quantile(0.3)) - 0.3).abs() < 1e-7);
+        assert!((calc_normal_cdf(calc_normal_quantile(0.999)) - 0.999).abs() < 1e-7);
+    }
+    #[test]
+    fn hierarchical_clustering_tests() {
+        let data: Vec<Vec<f64>> = vec![vec![0.0], vec![1.0], vec![5.0], vec![6.0], vec![20.0]];
+
+        // Closest observations are merged first, merged clusters are numbered from 5
+        let single = hierarchical_clustering(&data, Linkage::Single);
+        assert_eq!(single, vec![(0, 1, 1.0), (2, 3, 1.0), (5, 6, 4.0), (4, 7, 14.0)]);
+
+        let complete = hierarchical_clustering(&data, Linkage::Complete);
+        assert_eq!(complete, vec![(0, 1, 1.0), (2, 3, 1.0), (5, 6, 6.0), (4, 7, 20.0)]);
+
+        let average = hierarchical_clustering(&data, Linkage::Average);
+        assert_eq!(average, vec![(0, 1, 1.0), (2, 3, 1.0), (5, 6, 5.0), (4, 7, 17.0)]);
+
+        // Distances use every dimension of the observations
+        let data_2: Vec<Vec<f64>> = vec![vec![0.0, 0.0], vec![3.0, 4.0]];
+        assert_eq!(hierarchical_clustering(&data_2, Linkage::Single), vec![(0, 1, 5.0)]);
+    }
+    #[test]
+    fn sample_curve_tests() {
+        // Straight line needs no extra points
+        let lines_1 = sample_curve(&|t: f64| (t, 2.0 * t), 0.0, 1.0);
+        assert_eq!(lines_1.len(), 1);
+        assert_eq!(lines_1[0].len(), 65);
+        assert_eq!(lines_1[0][0], (0.0, 0.0));
+        assert_eq!(lines_1[0][64], (1.0, 2.0));
+
+        // More points are used where the curve bends
+        let lines_2 = sample_curve(&|t: f64| (t, (t * 10.0).sin()), 0.0, 10.0);
+        assert_eq!(lines_2.len(), 1);
+        assert!(lines_2[0].len() > 65);
+
+        // Line is broken at a jump
+        let lines_3 = sample_curve(&|t: f64| (t, if t < 0.3 { 0.0 } else { 1.0 }), 0.0, 1.0);
+        assert_eq!(lines_3.len(), 2);
+
+        // Line is broken at non-finite values
+        let lines_4 = sample_curve(&|t: f64| (t, 1.0 / t), -1.0, 1.0);
+        assert_eq!(lines_4.len(), 2);
+        let lines_5 = sample_curve(&|t: f64| (t, t.sqrt()), -1.0, 1.0);
+        assert_eq!(lines_5.len(), 1);
+        assert!(lines_5[0].iter().all(|&(x, _)| x >= 0.0));
+    }
+    #[test]
     fn remove_outliers_tests() {
         // No outlier
         let vec_1: Vec<f64> = vec![1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0];
@@ -19,6 +19,31 @@ pub(in chart_builder) enum ChartType {
     Line(LineChart),
     XYScat(XYScatterPlot),
     Bubble(BubbleChart),
+    Contour(ContourPlot),
+    Violin(ViolinPlot),
+    Candlestick(CandlestickChart),
+    Gantt(GanttChart),
+    Waterfall(WaterfallChart),
+    Funnel(FunnelChart),
+    PolarArea(PolarAreaChart),
+    Polar(PolarPlot),
+    Stem(StemPlot),
+    Lollipop(LollipopChart),
+    Sankey(SankeyChart),
+    Sunburst(SunburstChart),
+    ParallelCoordinates(ParallelCoordinates),
+    ScatterMatrix(ScatterMatrix),
+    Hist2D(Histogram2D),
+    Hexbin(HexbinPlot),
+    Density(DensityPlot),
+    Ecdf(EcdfPlot),
+    QQ(QQPlot),
+    Gauge(GaugeChart),
+    Bullet(BulletChart),
+    Calendar(CalendarHeatmap),
+    Dendrogram(Dendrogram),
+    Quiver(QuiverPlot),
+    Function(FunctionPlot),
 }
 
 /*
@@ -80,6 +105,56 @@ fn build_ui(application: &gtk::Application, chart_type: ChartType) {
                                 c.draw_chart(&drawing_area); },
         ChartType::Bubble(c) => { window_setup(c.get_chart_prop(), &window);
                                 c.draw_chart(&drawing_area); },
+        ChartType::Contour(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Violin(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Candlestick(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Gantt(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Waterfall(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Funnel(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::PolarArea(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Polar(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Stem(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Lollipop(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Sankey(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Sunburst(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::ParallelCoordinates(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::ScatterMatrix(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Hist2D(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Hexbin(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Density(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Ecdf(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::QQ(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Gauge(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Bullet(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Calendar(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Dendrogram(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Quiver(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
+        ChartType::Function(c) => { window_setup(c.get_chart_prop(), &window);
+                                c.draw_chart(&drawing_area); },
     };
 
     window.connect_delete_event(clone!(window => move |_, _| {
@@ -364,6 +364,54 @@ pub fn vec_keep_where<T: Clone>(vec1: &Vec<T>, index_vec:  &Vec<usize>) -> Vec<T
 }
 
 
+/// Returns the number of days since 1970-01-01 for a date string in the format ```YYYY-MM-DD```.
+///
+/// ```date``` is the date string to be converted, e.g. "2018-03-21".
+///
+/// Dates converted to numbers can be used as data on continuous axes.
+pub fn date_to_num(date: &str) -> f64 {
+    let parts: Result<Vec<i64>, _> = date.trim().split('-').map(|x| x.parse()).collect();
+    let parts = parts.expect("There was an error converting a date to a number, dates must be in the format YYYY-MM-DD");
+    if parts.len() != 3 {
+        panic!("Dates must be in the format YYYY-MM-DD: {:?}", date);
+    }
+    let (year, month, day) = (parts[0], parts[1], parts[2]);
+
+    // Days from civil algorithm - years are counted from March so the leap day is at the end of the year
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    (era * 146097 + doe - 719468) as f64
+}
+
+/// Returns a date string in the format ```YYYY-MM-DD``` for a number of days since 1970-01-01.
+///
+/// ```num``` is the number of days to be converted, any fraction of a day is ignored.
+pub fn num_to_date(num: f64) -> String {
+    // Civil from days algorithm - inverse of date_to_num()
+    let z = num.floor() as i64 + 719468;
+    let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { yoe + era * 400 + 1 } else { yoe + era * 400 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Converts all elements of a ```Vec<String>``` of dates in the format ```YYYY-MM-DD``` to the number of days since 1970-01-01.
+///
+/// ```vec1``` is the vector of dates to be converted.
+pub fn vec_date_to_num(vec1: &Vec<String>) -> Vec<f64> {
+    vec1.iter().map(|x| date_to_num(x)).collect()
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -516,4 +564,23 @@ mod tests {
         let headers_res = get_headers(1, 4, "./resources/data_parser_tests.csv");
         assert_eq!(headers_res, result);
     }
+    #[test]
+    fn date_tests() {
+        assert_eq!(date_to_num("1970-01-01"), 0.0);
+        assert_eq!(date_to_num("1970-01-02"), 1.0);
+        assert_eq!(date_to_num("1969-12-31"), -1.0);
+        assert_eq!(date_to_num("2000-03-01"), 11017.0);
+        assert_eq!(date_to_num("2018-03-21"), 17611.0);
+
+        assert_eq!(num_to_date(0.0), "1970-01-01");
+        assert_eq!(num_to_date(-1.0), "1969-12-31");
+        assert_eq!(num_to_date(11016.0), "2000-02-29");
+        assert_eq!(num_to_date(17611.5), "2018-03-21");
+
+        let dates = vec![String::from("2016-02-28"), String::from("2016-02-29"), String::from("2016-03-01")];
+        let nums = vec_date_to_num(&dates);
+        assert_eq!(nums, vec![16859.0, 16860.0, 16861.0]);
+        let back: Vec<String> = nums.iter().map(|&x| num_to_date(x)).collect();
+        assert_eq!(back, dates);
+    }
 }
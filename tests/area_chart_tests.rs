@@ -41,4 +41,16 @@ fn area_chart_tests() {
     multi_area_3.chart_prop.set_show_legend(true);
     multi_area_3.chart_prop.set_legend_values(vec![String::from("Location 1"), String::from("Location 2"), String::from("Location 3")]);
     multi_area_3.draw();
+
+    let mut step_area_1 = chart_builder::AreaChart::new(String::from("Test Area Chart 7"), data_1.clone(), vec![data_7.clone()]);
+    step_area_1.set_step_mode(chart_builder::StepMode::Pre);
+    step_area_1.draw();
+
+    let mut step_area_2 = chart_builder::AreaChart::new(String::from("Test Area Chart 8"), data_1.clone(), vec![data_7.clone()]);
+    step_area_2.set_step_mode(chart_builder::StepMode::Post);
+    step_area_2.draw();
+
+    let mut step_area_3 = chart_builder::AreaChart::new(String::from("Test Area Chart 9"), data_1.clone(), vec![data_7.clone()]);
+    step_area_3.set_step_mode(chart_builder::StepMode::Mid);
+    step_area_3.draw();
 }
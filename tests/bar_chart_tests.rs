@@ -41,4 +41,13 @@ fn bar_chart_tests() {
     multi_bar_3.chart_prop.set_show_legend(true);
     multi_bar_3.chart_prop.set_legend_values(vec![String::from("Location 1"), String::from("Location 2"), String::from("Location 3")]);
     multi_bar_3.draw();
+
+    let mut error_bar_1 = chart_builder::VerticalBarChart::new(String::from("Test Bar Chart 7"), data_1.clone(), vec![data_7.clone(), data_8.clone()]);
+    error_bar_1.set_y_errors(vec![vec![5.0, 3.0, 8.0, 2.0, 6.0], vec![4.0, 4.0, 4.0, 4.0, 4.0]]);
+    error_bar_1.draw();
+
+    let mut error_bar_2 = chart_builder::VerticalBarChart::new(String::from("Test Bar Chart 8"), data_1.clone(), vec![data_10.clone()]);
+    error_bar_2.set_y_errors_asymmetric(vec![vec![2.0, 8.0, 3.0, 1.0, 5.0]], vec![vec![10.0, 2.0, 6.0, 4.0, 1.0]]);
+    error_bar_2.set_error_cap_width(0.04);
+    error_bar_2.draw();
 }
@@ -0,0 +1,38 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn bullet_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 5, "./resources/bullet_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 5, "./resources/bullet_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 5, "./resources/bullet_chart_tests.csv");
+    let mut data_4: Vec<Vec<f64>> = vec![Vec::new(); 5];
+    for col in 3..6 {
+        let range = data_parser::get_num_col(col, 0, 5, "./resources/bullet_chart_tests.csv");
+        for i in 0..5 {
+            data_4[i].push(range[i]);
+        }
+    }
+
+    let bullet_1 = chart_builder::BulletChart::new(String::from("Test Bullet Chart 1"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone());
+    bullet_1.draw();
+
+    let mut bullet_2 = chart_builder::BulletChart::new(String::from("Test Bullet Chart 2"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone());
+    bullet_2.set_vertical(true);
+    bullet_2.draw();
+
+    // measures with a common scale
+    let mut bullet_3 = chart_builder::BulletChart::new(String::from("Test Bullet Chart 3"), vec![data_1[0].clone(), data_1[2].clone()],
+        vec![data_2[0], data_2[2]], vec![data_3[0], data_3[2]], vec![data_4[0].clone(), data_4[2].clone()]);
+    bullet_3.set_shared_scale(true);
+    bullet_3.axis_prop.set_x_axis_title(String::from("Value"));
+    bullet_3.draw();
+
+    let mut bullet_4 = bullet_3.clone();
+    bullet_4.set_vertical(true);
+    bullet_4.axis_prop.set_y_axis_title(String::from("Value"));
+    bullet_4.draw();
+}
@@ -0,0 +1,24 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn calendar_heatmap_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 435, "./resources/calendar_heatmap_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 435, "./resources/calendar_heatmap_tests.csv");
+
+    // single year
+    let calendar_1 = chart_builder::CalendarHeatmap::new(String::from("Test Calendar Heatmap 1"), data_1[0..200].to_vec(), data_2[0..200].to_vec());
+    calendar_1.draw();
+
+    // multiple years stacked
+    let mut calendar_2 = chart_builder::CalendarHeatmap::new(String::from("Test Calendar Heatmap 2"), data_1.clone(), data_2.clone());
+    calendar_2.chart_prop.set_screen_size(900.0, 500.0);
+    calendar_2.draw();
+
+    let mut calendar_3 = chart_builder::CalendarHeatmap::new(String::from("Test Calendar Heatmap 3"), data_1.clone(), data_2.clone());
+    calendar_3.chart_prop.set_show_legend(false);
+    calendar_3.draw();
+}
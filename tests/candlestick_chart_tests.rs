@@ -0,0 +1,47 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn candlestick_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 10, "./resources/candlestick_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 10, "./resources/candlestick_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 10, "./resources/candlestick_chart_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 10, "./resources/candlestick_chart_tests.csv");
+    let data_5 = data_parser::get_num_col(4, 0, 10, "./resources/candlestick_chart_tests.csv");
+    let candlestick_1 = chart_builder::CandlestickChart::new(String::from("Test Candlestick Chart 1"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone(), data_5.clone());
+    candlestick_1.draw();
+
+    let mut candlestick_2 = chart_builder::CandlestickChart::from_csv(String::from("Test Candlestick Chart 2"), 0, 1, 2, 3, 4, 0, 40, "./resources/candlestick_chart_tests.csv");
+    candlestick_2.set_volume(data_parser::get_num_col(5, 0, 40, "./resources/candlestick_chart_tests.csv"));
+    candlestick_2.axis_prop.set_x_axis_title(String::from("Date"));
+    candlestick_2.axis_prop.set_y_axis_title(String::from("Price"));
+    candlestick_2.draw();
+
+    let mut ohlc_1 = chart_builder::CandlestickChart::from_csv(String::from("Test OHLC Chart 1"), 0, 1, 2, 3, 4, 0, 40, "./resources/candlestick_chart_tests.csv");
+    ohlc_1.set_ohlc_bars(true);
+    ohlc_1.set_up_colour(0.0, 0.0, 0.0);
+    ohlc_1.set_down_colour(0.5, 0.5, 0.5);
+    ohlc_1.draw();
+
+    // More volumes than periods of price data
+    let mut ohlc_2 = chart_builder::CandlestickChart::new(String::from("Test OHLC Chart 2"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone(), data_5.clone());
+    ohlc_2.set_volume(data_parser::get_num_col(5, 0, 40, "./resources/candlestick_chart_tests.csv"));
+    ohlc_2.set_ohlc_bars(true);
+    ohlc_2.draw();
+
+    // Dates on a time scale, with gaps at weekends
+    let dates = data_parser::get_str_col(0, 0, 40, "./resources/candlestick_chart_tests.csv");
+    let mut candlestick_3 = chart_builder::CandlestickChart::from_dates(String::from("Test Candlestick Chart 3"), dates.clone(),
+        data_parser::get_num_col(1, 0, 40, "./resources/candlestick_chart_tests.csv"),
+        data_parser::get_num_col(2, 0, 40, "./resources/candlestick_chart_tests.csv"),
+        data_parser::get_num_col(3, 0, 40, "./resources/candlestick_chart_tests.csv"),
+        data_parser::get_num_col(4, 0, 40, "./resources/candlestick_chart_tests.csv"));
+    candlestick_3.set_volume(data_parser::get_num_col(5, 0, 40, "./resources/candlestick_chart_tests.csv"));
+    candlestick_3.draw();
+
+    let candlestick_4 = chart_builder::CandlestickChart::from_dates(String::from("Test Candlestick Chart 4"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone(), data_5.clone());
+    candlestick_4.draw();
+}
@@ -0,0 +1,48 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn contour_plot_tests() {
+    let x_data = data_parser::get_num_row(0, 1, 8, "./resources/contour_plot_tests.csv");
+    let y_data = data_parser::get_num_col(0, 1, 8, "./resources/contour_plot_tests.csv");
+    let mut z_data = Vec::new();
+    for j in 1..8 {
+        z_data.push(data_parser::get_num_row(j, 1, 8, "./resources/contour_plot_tests.csv"));
+    }
+    let contour_1 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 1"), x_data.clone(), y_data.clone(), z_data.clone());
+    contour_1.draw();
+
+    let mut contour_2 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 2"), x_data.clone(), y_data.clone(), z_data.clone());
+    contour_2.set_filled(true);
+    contour_2.set_show_labels(true);
+    contour_2.draw();
+
+    let mut contour_3 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 3"), x_data.clone(), y_data.clone(), z_data.clone());
+    contour_3.set_levels(vec![1.0, 2.5, 5.0, 7.5]);
+    contour_3.set_show_labels(true);
+    contour_3.chart_prop.set_show_legend(false);
+    contour_3.draw();
+
+    // Saddle shaped field z = x * y with a gap in the data
+    let grid: Vec<f64> = (0..21).map(|i| -1.0 + 0.1 * (i as f64)).collect();
+    let mut saddle: Vec<Vec<f64>> = grid.iter().map(|&y| grid.iter().map(|&x| x * y).collect()).collect();
+    saddle[10][10] = 0.0 / 0.0;
+    let mut contour_4 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 4"), grid.clone(), grid.clone(), saddle);
+    contour_4.set_filled(true);
+    contour_4.draw();
+
+    // Unevenly spaced levels label the colour bar at each band edge, non finite levels are ignored
+    let mut contour_5 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 5"), x_data.clone(), y_data.clone(), z_data.clone());
+    contour_5.set_levels(vec![0.0 / 0.0, 1.0, 1.5, 6.0]);
+    contour_5.set_filled(true);
+    contour_5.draw();
+
+    // Rows without any finite values are gaps in the field
+    let mut gappy = z_data.clone();
+    gappy[0] = vec![0.0 / 0.0; x_data.len()];
+    let contour_6 = chart_builder::ContourPlot::new(String::from("Test Contour Plot 6"), x_data.clone(), y_data.clone(), gappy);
+    contour_6.draw();
+}
@@ -0,0 +1,36 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn dendrogram_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 18, "./resources/dendrogram_tests.csv");
+    let mut data_2: Vec<Vec<f64>> = vec![Vec::new(); 18];
+    for col in 1..4 {
+        let values = data_parser::get_num_col(col, 0, 18, "./resources/dendrogram_tests.csv");
+        for i in 0..18 {
+            data_2[i].push(values[i]);
+        }
+    }
+
+    let dendrogram_1 = chart_builder::Dendrogram::new(String::from("Test Dendrogram 1"), data_1.clone(), data_2.clone());
+    dendrogram_1.draw();
+
+    let mut dendrogram_2 = chart_builder::Dendrogram::new(String::from("Test Dendrogram 2"), data_1.clone(), data_2.clone());
+    dendrogram_2.set_linkage(chart_builder::Linkage::Complete);
+    dendrogram_2.set_colour_threshold(4.0);
+    dendrogram_2.draw();
+
+    let mut dendrogram_3 = chart_builder::Dendrogram::new(String::from("Test Dendrogram 3"), data_1.clone(), data_2.clone());
+    dendrogram_3.set_linkage(chart_builder::Linkage::Single);
+    dendrogram_3.set_colour_threshold(2.0);
+    dendrogram_3.set_horizontal(true);
+    dendrogram_3.draw();
+
+    // every observation appears once in the leaf order
+    let mut leaf_order = dendrogram_3.get_leaf_order();
+    leaf_order.sort();
+    assert_eq!(leaf_order, (0..18).collect::<Vec<usize>>());
+}
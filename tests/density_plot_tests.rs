@@ -0,0 +1,38 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn density_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 150, "./resources/density_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 150, "./resources/density_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 150, "./resources/density_plot_tests.csv");
+    let data_4 = data_parser::get_headers(0, 3, "./resources/density_plot_tests.csv");
+
+    let density_1 = chart_builder::DensityPlot::new(String::from("Test Density Plot 1"), vec![data_1.clone()]);
+    density_1.draw();
+
+    let mut density_2 = chart_builder::DensityPlot::new(String::from("Test Density Plot 2"), vec![data_1.clone(), data_2.clone(), data_3.clone()]);
+    density_2.set_fill(true);
+    density_2.set_show_rug(true);
+    density_2.chart_prop.set_legend_values(data_4.clone());
+    density_2.draw();
+
+    let mut density_3 = chart_builder::DensityPlot::new(String::from("Test Density Plot 3"), vec![data_2.clone()]);
+    density_3.set_kernel(chart_builder::Kernel::Epanechnikov);
+    density_3.set_bandwidth(chart_builder::Bandwidth::Scott);
+    density_3.draw();
+
+    let mut density_4 = chart_builder::DensityPlot::new(String::from("Test Density Plot 4"), vec![data_2.clone(), data_3.clone()]);
+    density_4.set_bandwidth(chart_builder::Bandwidth::Manual(1.5));
+    density_4.set_show_rug(true);
+    density_4.draw();
+
+    // Bandwidths that are not positive are ignored
+    let mut density_5 = chart_builder::DensityPlot::new(String::from("Test Density Plot 5"), vec![data_2.clone()]);
+    density_5.set_bandwidth(chart_builder::Bandwidth::Manual(0.0));
+    density_5.set_bandwidth(chart_builder::Bandwidth::Manual(-1.0));
+    density_5.draw();
+}
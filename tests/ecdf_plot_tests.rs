@@ -0,0 +1,30 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn ecdf_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 300, "./resources/ecdf_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 300, "./resources/ecdf_plot_tests.csv");
+    let data_3 = data_parser::get_headers(0, 2, "./resources/ecdf_plot_tests.csv");
+
+    let ecdf_1 = chart_builder::EcdfPlot::new(String::from("Test ECDF Plot 1"), vec![data_1.clone()]);
+    ecdf_1.draw();
+
+    let mut ecdf_2 = chart_builder::EcdfPlot::new(String::from("Test ECDF Plot 2"), vec![data_1.clone(), data_2.clone()]);
+    ecdf_2.set_percentile_markers(vec![0.5, 0.9, 0.99]);
+    ecdf_2.chart_prop.set_legend_values(data_3.clone());
+    ecdf_2.draw();
+
+    let mut ecdf_3 = chart_builder::EcdfPlot::new(String::from("Test ECDF Plot 3"), vec![data_1.clone(), data_2.clone()]);
+    ecdf_3.set_complementary(true);
+    ecdf_3.set_percentile_markers(vec![0.5, 0.9, 0.99]);
+    ecdf_3.draw();
+
+    // Percentiles outside of 0 to 1 are ignored and empty series are not drawn
+    let mut ecdf_4 = chart_builder::EcdfPlot::new(String::from("Test ECDF Plot 4"), vec![data_1.clone(), Vec::new()]);
+    ecdf_4.set_percentile_markers(vec![0.5, 90.0, -0.1]);
+    ecdf_4.draw();
+}
@@ -0,0 +1,39 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn function_plot_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 3, "./resources/function_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 3, "./resources/function_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 3, "./resources/function_plot_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 3, "./resources/function_plot_tests.csv");
+
+    // functions built from data
+    let mut function_1 = chart_builder::FunctionPlot::new(String::from("Test Function Plot 1"), -5.0, 5.0);
+    for i in 0..3 {
+        let (amplitude, frequency, phase) = (data_2[i], data_3[i], data_4[i]);
+        function_1.add_function(move |x| amplitude * (frequency * x + phase).sin());
+    }
+    function_1.chart_prop.set_legend_values(data_1.clone());
+    function_1.chart_prop.set_show_legend(true);
+    function_1.draw();
+
+    // discontinuities and non-finite values
+    let mut function_2 = chart_builder::FunctionPlot::new(String::from("Test Function Plot 2"), -3.0, 3.0);
+    function_2.add_function(|x| 1.0 / x);
+    function_2.add_function(|x| x.sqrt());
+    function_2.add_function(|x| x.floor());
+    function_2.add_function(|x| x.tan());
+    function_2.axis_prop.set_y_axis_bounds(-5.0, 5.0);
+    function_2.draw();
+
+    // parametric curves
+    let mut function_3 = chart_builder::FunctionPlot::new(String::from("Test Function Plot 3"), -1.0, 1.0);
+    function_3.add_parametric(|t| (t.cos(), t.sin()), 0.0, 2.0 * std::f64::consts::PI);
+    function_3.add_parametric(|t| ((3.0 * t).sin(), (2.0 * t).sin()), 0.0, 2.0 * std::f64::consts::PI);
+    function_3.add_function(|x| x * x - 0.5);
+    function_3.draw();
+}
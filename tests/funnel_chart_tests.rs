@@ -0,0 +1,27 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn funnel_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 5, "./resources/funnel_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 5, "./resources/funnel_chart_tests.csv");
+    let funnel_1 = chart_builder::FunnelChart::new(String::from("Test Funnel Chart 1"), data_1.clone(), data_2.clone());
+    funnel_1.draw();
+
+    let data_3 = data_parser::get_str_col(2, 0, 5, "./resources/funnel_chart_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 5, "./resources/funnel_chart_tests.csv");
+    let mut funnel_2 = chart_builder::FunnelChart::new(String::from("Test Funnel Chart 2"), data_3.clone(), data_4.clone());
+    funnel_2.chart_prop.set_legend_values(data_3.clone());
+    funnel_2.chart_prop.set_show_legend(true);
+    funnel_2.draw();
+
+    // No percentages of a stage with a value of zero
+    let funnel_3 = chart_builder::FunnelChart::new(String::from("Test Funnel Chart 3"), data_1.clone(), vec![0.0, 40.0, 0.0, 10.0, 5.0]);
+    funnel_3.draw();
+
+    let funnel_4 = chart_builder::FunnelChart::new(String::from("Test Funnel Chart 4"), Vec::new(), Vec::new());
+    funnel_4.draw();
+}
@@ -0,0 +1,32 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn gantt_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 6, "./resources/gantt_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 6, "./resources/gantt_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 6, "./resources/gantt_chart_tests.csv");
+    let gantt_1 = chart_builder::GanttChart::new(String::from("Test Gantt Chart 1"), data_1.clone(), data_2.clone(), data_3.clone());
+    gantt_1.draw();
+
+    let data_4 = data_parser::get_str_col(3, 0, 6, "./resources/gantt_chart_tests.csv");
+    let data_5 = data_parser::get_num_col(4, 0, 6, "./resources/gantt_chart_tests.csv");
+    let mut gantt_2 = chart_builder::GanttChart::new(String::from("Test Gantt Chart 2"), data_1.clone(), data_2.clone(), data_3.clone());
+    gantt_2.set_groups(data_4.clone());
+    gantt_2.set_progress(data_5.clone());
+    gantt_2.set_dependencies(vec![(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (5, 40)]);
+    gantt_2.set_today(15.0);
+    gantt_2.axis_prop.set_x_axis_title(String::from("Week"));
+    gantt_2.draw();
+
+    let data_6 = data_parser::get_str_col(5, 0, 6, "./resources/gantt_chart_tests.csv");
+    let data_7 = data_parser::get_str_col(6, 0, 6, "./resources/gantt_chart_tests.csv");
+    let mut gantt_3 = chart_builder::GanttChart::from_dates(String::from("Test Gantt Chart 3"), data_1.clone(), data_6.clone(), data_7.clone());
+    gantt_3.set_groups(data_4.clone());
+    gantt_3.set_dependencies(vec![(0, 1), (1, 2), (1, 3), (3, 4), (4, 5)]);
+    gantt_3.set_today(data_parser::date_to_num("2018-05-01"));
+    gantt_3.draw();
+}
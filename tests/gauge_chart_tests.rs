@@ -0,0 +1,33 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn gauge_chart_tests() {
+    let data_1 = data_parser::get_num_col(1, 0, 3, "./resources/gauge_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(2, 0, 3, "./resources/gauge_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(3, 0, 3, "./resources/gauge_chart_tests.csv");
+
+    let gauge_1 = chart_builder::GaugeChart::new(String::from("Test Gauge Chart 1"), data_1[0], data_2[0], data_3[0]);
+    gauge_1.draw();
+
+    let mut gauge_2 = chart_builder::GaugeChart::new(String::from("Test Gauge Chart 2"), data_1[0], data_2[0], data_3[0]);
+    gauge_2.set_arc_angle(270.0);
+    gauge_2.set_thresholds(vec![60.0, 85.0]);
+    gauge_2.chart_prop.set_legend_values(vec![String::from("Low"), String::from("Target"), String::from("High")]);
+    gauge_2.chart_prop.set_show_legend(true);
+    gauge_2.draw();
+
+    let mut gauge_3 = chart_builder::GaugeChart::new(String::from("Test Gauge Chart 3"), data_1[1], data_2[1], data_3[1]);
+    gauge_3.set_thresholds(vec![0.5, 0.9]);
+    gauge_3.set_show_needle(true);
+    gauge_3.draw();
+
+    // value above the max is drawn at the end of the dial
+    let mut gauge_4 = chart_builder::GaugeChart::new(String::from("Test Gauge Chart 4"), data_1[2], data_2[2], data_3[2]);
+    gauge_4.set_arc_angle(270.0);
+    gauge_4.set_show_needle(true);
+    gauge_4.draw();
+}
@@ -0,0 +1,25 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn hexbin_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 400, "./resources/hexbin_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 400, "./resources/hexbin_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 400, "./resources/hexbin_plot_tests.csv");
+
+    let hexbin = chart_builder::HexbinPlot::new(String::from("Test Hexbin Plot 1"), data_1.clone(), data_2.clone());
+    hexbin.draw();
+
+    let mut hexbin = chart_builder::HexbinPlot::new(String::from("Test Hexbin Plot 2"), data_1.clone(), data_2.clone());
+    hexbin.set_grid_size(12);
+    hexbin.draw();
+
+    let mut hexbin = chart_builder::HexbinPlot::new(String::from("Test Hexbin Plot 3"), data_1.clone(), data_2.clone());
+    hexbin.set_hex_width(4.0);
+    hexbin.set_aggregate_values(data_3.clone());
+    hexbin.chart_prop.set_show_legend(false);
+    hexbin.draw();
+}
@@ -0,0 +1,25 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn histogram_2d_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 400, "./resources/histogram_2d_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 400, "./resources/histogram_2d_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 400, "./resources/histogram_2d_tests.csv");
+
+    let hist = chart_builder::Histogram2D::new(String::from("Test 2D Histogram 1"), data_1.clone(), data_2.clone());
+    hist.draw();
+
+    let mut hist = chart_builder::Histogram2D::new(String::from("Test 2D Histogram 2"), data_1.clone(), data_2.clone());
+    hist.set_bin_counts(10, 15);
+    hist.draw();
+
+    let mut hist = chart_builder::Histogram2D::new(String::from("Test 2D Histogram 3"), data_1.clone(), data_2.clone());
+    hist.set_bin_sizes(3.0, 7.0);
+    hist.set_aggregate_values(data_3.clone());
+    hist.chart_prop.set_show_legend(false);
+    hist.draw();
+}
@@ -9,4 +9,8 @@ fn histogram_tests() {
     let data_1 = data_parser::get_num_col(1, 0, 1000, "./resources/histogram_tests.csv");
     let histogram_1 = chart_builder::Histogram::new(String::from("Test Histogram 1"), data_1.clone());
     histogram_1.draw();
+
+    let mut histogram_2 = chart_builder::Histogram::new(String::from("Test Histogram 2"), data_1.clone());
+    histogram_2.set_show_density(true);
+    histogram_2.draw();
 }
@@ -41,4 +41,21 @@ fn line_chart_tests() {
     multi_line_3.chart_prop.set_show_legend(true);
     multi_line_3.chart_prop.set_legend_values(vec![String::from("Location 1"), String::from("Location 2"), String::from("Location 3")]);
     multi_line_3.draw();
+
+    let mut step_line_1 = chart_builder::LineChart::new(String::from("Test Line Chart 7"), data_1.clone(), vec![data_7.clone(), data_8.clone()]);
+    step_line_1.set_step_mode(chart_builder::StepMode::Pre);
+    step_line_1.draw();
+
+    let mut step_line_2 = chart_builder::LineChart::new(String::from("Test Line Chart 8"), data_1.clone(), vec![data_7.clone(), data_8.clone()]);
+    step_line_2.set_step_mode(chart_builder::StepMode::Post);
+    step_line_2.draw();
+
+    let mut step_line_3 = chart_builder::LineChart::new(String::from("Test Line Chart 9"), data_1.clone(), vec![data_7.clone(), data_8.clone()]);
+    step_line_3.set_step_mode(chart_builder::StepMode::Mid);
+    step_line_3.draw();
+
+    let mut error_line_1 = chart_builder::LineChart::new(String::from("Test Line Chart 10"), data_1.clone(), vec![data_7.clone(), data_8.clone()]);
+    error_line_1.set_y_errors_asymmetric(vec![vec![5.0, 3.0, 8.0, 2.0, 6.0]], vec![vec![2.0, 6.0, 4.0, 9.0, 3.0]]);
+    error_line_1.set_error_cap_width(0.0);
+    error_line_1.draw();
 }
@@ -0,0 +1,24 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn lollipop_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 6, "./resources/lollipop_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 6, "./resources/lollipop_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 6, "./resources/lollipop_chart_tests.csv");
+
+    let lollipop = chart_builder::LollipopChart::new(String::from("Test Lollipop Chart 1"), data_1.clone(), vec![data_2.clone()]);
+    lollipop.draw();
+
+    let mut lollipop = chart_builder::LollipopChart::new(String::from("Test Lollipop Chart 2"), data_1.clone(), vec![data_2.clone(), data_3.clone()]);
+    lollipop.chart_prop.set_legend_values(vec![String::from("Sales"), String::from("Target")]);
+    lollipop.chart_prop.set_show_legend(true);
+    lollipop.draw();
+
+    let mut lollipop = chart_builder::LollipopChart::new(String::from("Test Lollipop Chart 3"), data_1.clone(), vec![data_2.clone()]);
+    lollipop.set_baseline(50.0);
+    lollipop.draw();
+}
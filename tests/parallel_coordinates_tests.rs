@@ -0,0 +1,28 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn parallel_coordinates_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 30, "./resources/parallel_coordinates_tests.csv");
+    let mut data_2: Vec<Vec<f64>> = Vec::new();
+    for col in 1..6 {
+        data_2.push(data_parser::get_num_col(col, 0, 30, "./resources/parallel_coordinates_tests.csv"));
+    }
+    let data_3 = data_parser::get_headers(1, 6, "./resources/parallel_coordinates_tests.csv");
+
+    let parallel = chart_builder::ParallelCoordinates::new(String::from("Test Parallel Coordinates 1"), data_3.clone(), data_2.clone());
+    parallel.draw();
+
+    let mut parallel = chart_builder::ParallelCoordinates::new(String::from("Test Parallel Coordinates 2"), data_3.clone(), data_2.clone());
+    parallel.set_groups(data_1.clone());
+    parallel.set_axis_order(vec![2, 0, 1, 4, 3]);
+    parallel.set_inverted_axes(vec![1]);
+    parallel.draw();
+
+    let mut parallel = chart_builder::ParallelCoordinates::new(String::from("Test Parallel Coordinates 3"), data_3.clone(), data_2.clone());
+    parallel.set_colour_values(data_2[4].clone());
+    parallel.draw();
+}
@@ -0,0 +1,26 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn polar_area_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 12, "./resources/polar_area_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 12, "./resources/polar_area_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 12, "./resources/polar_area_chart_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 12, "./resources/polar_area_chart_tests.csv");
+    let data_5 = data_parser::get_str_col(0, 0, 5, "./resources/polar_area_chart_tests.csv");
+    let data_6 = data_parser::get_num_col(2, 0, 5, "./resources/polar_area_chart_tests.csv");
+
+    let polar_area = chart_builder::PolarAreaChart::new(String::from("Test Polar Area Chart 1"), data_1.clone(), vec![data_2.clone()]);
+    polar_area.draw();
+
+    let polar_area = chart_builder::PolarAreaChart::new(String::from("Test Polar Area Chart 2"), data_5.clone(), vec![data_6.clone()]);
+    polar_area.draw();
+
+    let mut polar_area = chart_builder::PolarAreaChart::new(String::from("Test Polar Area Chart 3"), data_1.clone(), vec![data_3.clone(), data_4.clone(), data_2.clone()]);
+    polar_area.chart_prop.set_legend_values(vec![String::from("Wounds"), String::from("Other"), String::from("Preventable")]);
+    polar_area.chart_prop.set_show_legend(true);
+    polar_area.draw();
+}
@@ -0,0 +1,34 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn polar_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 12, "./resources/polar_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 12, "./resources/polar_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 12, "./resources/polar_plot_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 12, "./resources/polar_plot_tests.csv");
+
+    let polar = chart_builder::PolarPlot::new(String::from("Test Polar Plot 1"), vec![data_1.clone()], vec![data_2.clone()]);
+    polar.draw();
+
+    let mut polar = chart_builder::PolarPlot::new(String::from("Test Polar Plot 2"), vec![data_1.clone()], vec![data_2.clone()]);
+    polar.set_zero_direction(90.0);
+    polar.set_clockwise(true);
+    polar.set_show_lines(true);
+    polar.draw();
+
+    let mut polar = chart_builder::PolarPlot::new(String::from("Test Polar Plot 3"), vec![data_4.clone(), data_4.clone()], vec![data_3.clone(), data_2.clone()]);
+    polar.set_radians(true);
+    polar.set_show_lines(true);
+    polar.set_show_marks(false);
+    polar.chart_prop.set_legend_values(vec![String::from("Gain"), String::from("Speed")]);
+    polar.chart_prop.set_show_legend(true);
+    polar.draw();
+
+    // More series of angles than of distances
+    let polar = chart_builder::PolarPlot::new(String::from("Test Polar Plot 4"), vec![data_1.clone(), data_1.clone()], vec![data_2.clone()]);
+    polar.draw();
+}
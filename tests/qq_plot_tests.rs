@@ -0,0 +1,48 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn qq_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 120, "./resources/qq_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 120, "./resources/qq_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 80, "./resources/qq_plot_tests.csv");
+
+    let qq_1 = chart_builder::QQPlot::new(String::from("Test Q-Q Plot 1"), data_1.clone());
+    qq_1.draw();
+
+    let mut qq_2 = chart_builder::QQPlot::new(String::from("Test Q-Q Plot 2"), data_1.clone());
+    qq_2.set_show_envelope(true);
+    qq_2.draw();
+
+    let mut qq_3 = chart_builder::QQPlot::new(String::from("Test Q-Q Plot 3"), data_2.clone());
+    qq_3.set_distribution(chart_builder::Distribution::Exponential);
+    qq_3.set_show_envelope(true);
+    qq_3.draw();
+
+    let mut qq_4 = chart_builder::QQPlot::new(String::from("Test Q-Q Plot 4"), data_2.clone());
+    qq_4.set_distribution(chart_builder::Distribution::Uniform);
+    qq_4.draw();
+
+    let mut qq_5 = chart_builder::QQPlot::new(String::from("Test Q-Q Plot 5"), data_1.clone());
+    qq_5.set_reference_sample(data_3.clone());
+    qq_5.set_show_envelope(true);
+    qq_5.draw();
+
+    let mut pp_1 = chart_builder::QQPlot::new(String::from("Test P-P Plot 1"), data_1.clone());
+    pp_1.set_probability_plot(true);
+    pp_1.set_show_envelope(true);
+    pp_1.draw();
+
+    let mut pp_2 = chart_builder::QQPlot::new(String::from("Test P-P Plot 2"), data_2.clone());
+    pp_2.set_distribution(chart_builder::Distribution::Exponential);
+    pp_2.set_probability_plot(true);
+    pp_2.draw();
+
+    let mut pp_3 = chart_builder::QQPlot::new(String::from("Test P-P Plot 3"), data_1.clone());
+    pp_3.set_reference_sample(data_3.clone());
+    pp_3.set_probability_plot(true);
+    pp_3.draw();
+}
@@ -0,0 +1,26 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn quiver_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 121, "./resources/quiver_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 121, "./resources/quiver_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 121, "./resources/quiver_plot_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 121, "./resources/quiver_plot_tests.csv");
+
+    let quiver_1 = chart_builder::QuiverPlot::new(String::from("Test Quiver Plot 1"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone());
+    quiver_1.draw();
+
+    let mut quiver_2 = chart_builder::QuiverPlot::new(String::from("Test Quiver Plot 2"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone());
+    quiver_2.set_colour_by_magnitude(true);
+    quiver_2.set_key_magnitude(1.0);
+    quiver_2.draw();
+
+    let mut quiver_3 = chart_builder::QuiverPlot::new(String::from("Test Quiver Plot 3"), data_1.clone(), data_2.clone(), data_3.clone(), data_4.clone());
+    quiver_3.set_arrow_scale(0.02);
+    quiver_3.set_show_key(false);
+    quiver_3.draw();
+}
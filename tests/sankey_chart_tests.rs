@@ -0,0 +1,26 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn sankey_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 13, "./resources/sankey_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 12, "./resources/sankey_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 12, "./resources/sankey_chart_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 12, "./resources/sankey_chart_tests.csv");
+
+    let mut links: Vec<(usize, usize, f64)> = Vec::new();
+    for i in 0..data_2.len() {
+        links.push((data_2[i] as usize, data_3[i] as usize, data_4[i]));
+    }
+
+    let sankey = chart_builder::SankeyChart::new(String::from("Test Sankey Chart 1"), data_1.clone(), links.clone());
+    sankey.draw();
+
+    let sankey = chart_builder::SankeyChart::new(String::from("Test Sankey Chart 2"),
+        vec![String::from("Visit"), String::from("Sign Up"), String::from("Leave"), String::from("Purchase")],
+        vec![(0, 1, 400.0), (0, 2, 600.0), (1, 3, 150.0), (1, 2, 250.0)]);
+    sankey.draw();
+}
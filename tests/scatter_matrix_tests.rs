@@ -0,0 +1,27 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn scatter_matrix_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 30, "./resources/scatter_matrix_tests.csv");
+    let mut data_2: Vec<Vec<f64>> = Vec::new();
+    for col in 1..5 {
+        data_2.push(data_parser::get_num_col(col, 0, 30, "./resources/scatter_matrix_tests.csv"));
+    }
+    let data_3 = data_parser::get_headers(1, 5, "./resources/scatter_matrix_tests.csv");
+
+    let matrix = chart_builder::ScatterMatrix::new(String::from("Test Scatter Matrix 1"), data_3.clone(), data_2.clone());
+    matrix.draw();
+
+    let mut matrix = chart_builder::ScatterMatrix::new(String::from("Test Scatter Matrix 2"), data_3.clone(), data_2.clone());
+    matrix.set_groups(data_1.clone());
+    matrix.draw();
+
+    let mut matrix = chart_builder::ScatterMatrix::new(String::from("Test Scatter Matrix 3"), data_3[0..3].to_vec(), data_2[0..3].to_vec());
+    matrix.set_groups(data_1.clone());
+    matrix.set_diagonal_density(true);
+    matrix.draw();
+}
@@ -0,0 +1,24 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn stem_plot_tests() {
+    let data_1 = data_parser::get_num_col(0, 0, 16, "./resources/stem_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 16, "./resources/stem_plot_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 16, "./resources/stem_plot_tests.csv");
+
+    let stem = chart_builder::StemPlot::new(String::from("Test Stem Plot 1"), vec![data_1.clone()], vec![data_2.clone()]);
+    stem.draw();
+
+    let mut stem = chart_builder::StemPlot::new(String::from("Test Stem Plot 2"), vec![data_1.clone(), data_1.clone()], vec![data_2.clone(), data_3.clone()]);
+    stem.chart_prop.set_legend_values(vec![String::from("Signal"), String::from("Decay")]);
+    stem.chart_prop.set_show_legend(true);
+    stem.draw();
+
+    let mut stem = chart_builder::StemPlot::new(String::from("Test Stem Plot 3"), vec![data_1.clone()], vec![data_3.clone()]);
+    stem.set_baseline(4.0);
+    stem.draw();
+}
@@ -0,0 +1,31 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn sunburst_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 16, "./resources/sunburst_chart_tests.csv");
+    let data_2 = data_parser::get_str_col(1, 0, 16, "./resources/sunburst_chart_tests.csv");
+    let data_3 = data_parser::get_num_col(2, 0, 16, "./resources/sunburst_chart_tests.csv");
+    let parents: Vec<Option<usize>> = data_2.iter().map(|p| p.parse::<usize>().ok()).collect();
+
+    let sunburst = chart_builder::SunburstChart::new(String::from("Test Sunburst Chart 1"), data_1.clone(), parents.clone(), data_3.clone());
+    sunburst.draw();
+
+    let mut sunburst = chart_builder::SunburstChart::new(String::from("Test Sunburst Chart 2"),
+        vec![String::from("A"), String::from("B"), String::from("A1"), String::from("A2"), String::from("B1")],
+        vec![None, None, Some(0), Some(0), Some(1)],
+        vec![10.0, 5.0, 4.0, 3.0, 5.0]);
+    sunburst.chart_prop.set_legend_values(vec![String::from("A"), String::from("B")]);
+    sunburst.chart_prop.set_show_legend(true);
+    sunburst.draw();
+
+    // Fewer labels than segments
+    let sunburst = chart_builder::SunburstChart::new(String::from("Test Sunburst Chart 3"),
+        vec![String::from("A"), String::from("B")],
+        vec![None, None, Some(0), Some(0), Some(1)],
+        vec![10.0, 5.0, 4.0, 3.0, 5.0]);
+    sunburst.draw();
+}
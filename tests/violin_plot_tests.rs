@@ -0,0 +1,38 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn violin_plot_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 1, "./resources/violin_plot_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 60, "./resources/violin_plot_tests.csv");
+    let violin_1 = chart_builder::ViolinPlot::new(String::from("Test Violin Plot 1"), data_1.clone(), vec![data_2.clone()]);
+    violin_1.draw();
+
+    let data_3 = data_parser::get_str_col(0, 0, 3, "./resources/violin_plot_tests.csv");
+    let data_4 = data_parser::get_num_col(2, 0, 60, "./resources/violin_plot_tests.csv");
+    let data_5 = data_parser::get_num_col(3, 0, 60, "./resources/violin_plot_tests.csv");
+    let mut violin_2 = chart_builder::ViolinPlot::new(String::from("Test Violin Plot 2"), data_3.clone(), vec![data_2.clone(), data_4.clone(), data_5.clone()]);
+    violin_2.set_inner_box(false);
+    violin_2.set_quartile_lines(true);
+    violin_2.draw();
+
+    let mut violin_3 = chart_builder::ViolinPlot::new(String::from("Test Violin Plot 3"), data_3.clone(), vec![data_2.clone(), data_4.clone(), data_5.clone()]);
+    violin_3.set_bandwidth(1.0);
+    violin_3.set_bandwidth(0.0);
+    violin_3.set_quartile_lines(true);
+    violin_3.draw();
+
+    // No violin for an empty group
+    let violin_4 = chart_builder::ViolinPlot::new(String::from("Test Violin Plot 4"), data_3.clone(), vec![data_2.clone(), Vec::new(), data_5.clone()]);
+    violin_4.draw();
+
+    // Box plot data containing outliers
+    let data_6 = data_parser::get_str_col(0, 0, 2, "./resources/box_plot_tests.csv");
+    let data_7 = data_parser::get_num_col(3, 0, 15, "./resources/box_plot_tests.csv");
+    let data_8 = data_parser::get_num_col(2, 0, 10, "./resources/box_plot_tests.csv");
+    let violin_4 = chart_builder::ViolinPlot::new(String::from("Test Violin Plot 4"), data_6.clone(), vec![data_7.clone(), data_8.clone()]);
+    violin_4.draw();
+}
@@ -0,0 +1,23 @@
+extern crate rustplot;
+
+use rustplot::data_parser;
+use rustplot::chart_builder;
+use rustplot::chart_builder::Chart;
+
+#[test]
+fn waterfall_chart_tests() {
+    let data_1 = data_parser::get_str_col(0, 0, 8, "./resources/waterfall_chart_tests.csv");
+    let data_2 = data_parser::get_num_col(1, 0, 8, "./resources/waterfall_chart_tests.csv");
+    let waterfall_1 = chart_builder::WaterfallChart::new(String::from("Test Waterfall Chart 1"), data_1.clone(), data_2.clone());
+    waterfall_1.draw();
+
+    let mut waterfall_2 = chart_builder::WaterfallChart::new(String::from("Test Waterfall Chart 2"), data_1.clone(), data_2.clone());
+    waterfall_2.set_totals(vec![4, 7]);
+    waterfall_2.draw();
+
+    // Running total crossing zero
+    let data_3 = data_parser::get_str_col(2, 0, 8, "./resources/waterfall_chart_tests.csv");
+    let data_4 = data_parser::get_num_col(3, 0, 8, "./resources/waterfall_chart_tests.csv");
+    let waterfall_3 = chart_builder::WaterfallChart::new(String::from("Test Waterfall Chart 3"), data_3.clone(), data_4.clone());
+    waterfall_3.draw();
+}
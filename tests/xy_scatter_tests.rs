@@ -12,5 +12,10 @@ fn xy_scatter_tests() {
     xy.draw();
     xy.set_best_fit_line(true);
     xy.draw();
+
+    let mut xy = chart_builder::XYScatterPlot::new(String::from("Test XY Scatter Chart Error Bars"), vec![data_1.clone()], vec![data_2.clone()]);
+    xy.set_x_errors(vec![vec![2.0, 3.0, 1.5, 4.0, 2.5]]);
+    xy.set_y_errors_asymmetric(vec![vec![1.0, 2.0, 3.0, 1.0, 2.0]], vec![vec![4.0, 3.0, 2.0, 5.0, 1.0]]);
+    xy.draw();
     assert_eq!(true, true);
 }